@@ -0,0 +1,69 @@
+//! A stand-in for your app: it listens for the themer, and can also push
+//! styles to itself to show the round trip without the themer running.
+//!
+//! `cargo run -p egui-themer-live --example loopback`, then Connect from the
+//! themer with the default address.
+
+use eframe::egui::{self, Style, Visuals};
+use egui_themer_live::{Connection, DEFAULT_ADDR};
+
+fn main() -> eframe::Result {
+    eframe::run_native(
+        "egui-themer-live loopback",
+        eframe::NativeOptions::default(),
+        Box::new(|cc| {
+            egui_themer_live::listen(&cc.egui_ctx, DEFAULT_ADDR)?;
+            Ok(Box::<Loopback>::default())
+        }),
+    )
+}
+
+#[derive(Default)]
+struct Loopback {
+    checked: bool,
+    value: f32,
+    text: String,
+}
+
+impl eframe::App for Loopback {
+    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.heading("Listening on");
+            ui.monospace(DEFAULT_ADDR);
+            ui.separator();
+
+            ui.horizontal(|ui| {
+                for (name, visuals) in [
+                    ("Push Dark", Visuals::dark()),
+                    ("Push Light", Visuals::light()),
+                ] {
+                    if ui.button(name).clicked() {
+                        let style = Style {
+                            visuals,
+                            ..Default::default()
+                        };
+                        if let Err(err) =
+                            Connection::connect(DEFAULT_ADDR).and_then(|mut c| c.send(&style))
+                        {
+                            log::warn!("push failed: {err}");
+                        }
+                    }
+                }
+            });
+            ui.separator();
+
+            ui.checkbox(&mut self.checked, "A checkbox");
+            ui.add(egui::Slider::new(&mut self.value, 0.0..=100.0).text("A slider"));
+            ui.text_edit_singleline(&mut self.text);
+            ui.hyperlink_to("A link", "https://github.com/grantshandy/egui-themer");
+            egui::CollapsingHeader::new("A collapsing header").show(ui, |ui| {
+                ui.label("Hello!");
+            });
+
+            egui::Window::new("A window").show(ctx, |ui| {
+                ui.label("Windows pick up the theme too.");
+                let _ = ui.button("A button");
+            });
+        });
+    }
+}
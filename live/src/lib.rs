@@ -0,0 +1,78 @@
+//! Apply themes from [egui-themer](https://github.com/grantshandy/egui-themer)
+//! to your own app while it runs, instead of tuning against the demo windows.
+//!
+//! Call [`listen`] once at startup, then use the themer's Connect panel:
+//!
+//! ```no_run
+//! # fn setup(ctx: &egui::Context) -> std::io::Result<()> {
+//! egui_themer_live::listen(ctx, egui_themer_live::DEFAULT_ADDR)?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Styles are sent as one line of JSON each over a plain TCP connection, so
+//! both ends have to use the same egui version.
+
+use std::{
+    io::{self, BufRead, BufReader, Write},
+    net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs},
+    thread,
+};
+
+use egui::{Context, Style};
+
+/// Where the themer connects to unless told otherwise.
+pub const DEFAULT_ADDR: &str = "127.0.0.1:9977";
+
+/// Accept connections on `addr` for the rest of the program, setting each
+/// style received as `ctx`'s style. Returns the bound address, which is
+/// useful when binding port 0.
+pub fn listen(ctx: &Context, addr: impl ToSocketAddrs) -> io::Result<SocketAddr> {
+    let listener = TcpListener::bind(addr)?;
+    let addr = listener.local_addr()?;
+
+    let ctx = ctx.clone();
+    thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            let ctx = ctx.clone();
+            thread::spawn(move || receive(&ctx, stream));
+        }
+    });
+
+    Ok(addr)
+}
+
+fn receive(ctx: &Context, stream: TcpStream) {
+    for line in BufReader::new(stream).lines() {
+        let Ok(line) = line else {
+            return;
+        };
+
+        match serde_json::from_str::<Style>(&line) {
+            Ok(style) => {
+                ctx.set_style(style);
+                ctx.request_repaint();
+            }
+            Err(err) => log::warn!("ignoring a malformed style: {err}"),
+        }
+    }
+}
+
+/// The sending end, used by the themer.
+pub struct Connection {
+    stream: TcpStream,
+}
+
+impl Connection {
+    pub fn connect(addr: impl ToSocketAddrs) -> io::Result<Self> {
+        let stream = TcpStream::connect(addr)?;
+        stream.set_nodelay(true)?;
+        Ok(Self { stream })
+    }
+
+    pub fn send(&mut self, style: &Style) -> io::Result<()> {
+        let mut line = serde_json::to_vec(style)?;
+        line.push(b'\n');
+        self.stream.write_all(&line)
+    }
+}
@@ -0,0 +1,31 @@
+use std::time::{Duration, Instant};
+
+use egui::{Color32, Context, Style, Visuals};
+use egui_themer_live::Connection;
+
+#[test]
+fn round_trip() {
+    let ctx = Context::default();
+    let addr = egui_themer_live::listen(&ctx, "127.0.0.1:0").unwrap();
+
+    let mut style = Style {
+        visuals: Visuals::light(),
+        ..Default::default()
+    };
+    style.visuals.window_fill = Color32::from_rgb(1, 2, 3);
+    style.spacing.item_spacing.x = 13.0;
+
+    let mut connection = Connection::connect(addr).unwrap();
+    connection.send(&style).unwrap();
+
+    // `Style`'s `PartialEq` compares the number formatter by pointer
+    let json = |style: &Style| serde_json::to_value(style).unwrap();
+    let start = Instant::now();
+    while json(&ctx.style()) != json(&style) {
+        assert!(
+            start.elapsed() < Duration::from_secs(5),
+            "style never arrived"
+        );
+        std::thread::sleep(Duration::from_millis(10));
+    }
+}
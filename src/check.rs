@@ -0,0 +1,157 @@
+use std::{
+    fs,
+    path::PathBuf,
+    process::Command,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use eframe::{
+    egui::{Button, Context, Layout, RichText, ScrollArea, Ui},
+    emath::Align,
+};
+use egui_notify::Toasts;
+use egui_themer::codegen::EguiVersion;
+
+/// Slot the background check writes the checked source or the compiler errors to.
+type PendingCheck = Arc<Mutex<Option<Result<String, String>>>>;
+
+/// Optionally type-checks generated Rust in a scratch crate before it's saved.
+///
+/// The scratch crate lives in the system temp directory and is reused, so egui
+/// is only built by the first check.
+#[derive(Default)]
+pub struct CompileCheck {
+    enabled: bool,
+    pending: Option<PendingCheck>,
+    errors: Option<String>,
+}
+
+impl CompileCheck {
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn checking(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    pub fn option_ui(&mut self, ui: &mut Ui, version: EguiVersion) {
+        ui.horizontal(|ui| {
+            ui.label("Compile Check").on_hover_text(format!(
+                "Type-check the generated source against egui {} before saving. Runs cargo offline.",
+                version.name()
+            ));
+            ui.with_layout(Layout::right_to_left(Align::Min), |ui| {
+                ui.checkbox(&mut self.enabled, "")
+            });
+        });
+    }
+
+    /// Check `source` on a background thread.
+    pub fn start(&mut self, ctx: &Context, source: String, eframe: bool, version: EguiVersion) {
+        let pending = Arc::new(Mutex::new(None));
+        self.pending = Some(pending.clone());
+        self.errors = None;
+
+        let ctx = ctx.clone();
+        std::thread::spawn(move || {
+            let result = check_source(&source, eframe, version).map(|_| source);
+            *pending.lock().unwrap() = Some(result);
+            ctx.request_repaint();
+        });
+    }
+
+    /// Returns the checked source once it has compiled successfully.
+    pub fn poll(&mut self, toasts: &mut Toasts) -> Option<String> {
+        let result = self.pending.as_ref()?.lock().unwrap().take()?;
+        self.pending = None;
+
+        match result {
+            Ok(source) => Some(source),
+            Err(errors) => {
+                toasts
+                    .error("Generated source failed to compile")
+                    .set_duration(Some(Duration::from_secs(5)));
+                self.errors = Some(errors);
+                None
+            }
+        }
+    }
+
+    pub fn status_ui(&mut self, ui: &mut Ui) {
+        if self.checking() {
+            ui.horizontal(|ui| {
+                ui.spinner();
+                ui.label("Checking generated source…");
+            });
+        }
+
+        if self.errors.is_some() {
+            ui.horizontal(|ui| {
+                ui.colored_label(ui.visuals().error_fg_color, "Compile Errors");
+                ui.with_layout(Layout::right_to_left(Align::Min), |ui| {
+                    if ui.add(Button::new("🗙")).clicked() {
+                        self.errors = None;
+                    }
+                });
+            });
+        }
+
+        if let Some(errors) = &self.errors {
+            ScrollArea::both()
+                .id_source("compile_errors")
+                .max_height(150.0)
+                .show(ui, |ui| {
+                    ui.label(RichText::new(errors).monospace().small());
+                });
+        }
+    }
+}
+
+/// Type-check `source` as a module of a scratch crate depending on egui (or eframe).
+pub fn check_source(source: &str, eframe: bool, version: EguiVersion) -> Result<(), String> {
+    let dir = scratch_dir();
+    fs::create_dir_all(dir.join("src")).map_err(|err| err.to_string())?;
+
+    let dependency = if eframe { "eframe" } else { "egui" };
+    fs::write(
+        dir.join("Cargo.toml"),
+        format!(
+            "[package]\n\
+             name = \"egui-themer-check\"\n\
+             version = \"0.0.0\"\n\
+             edition = \"2021\"\n\
+             publish = false\n\
+             \n\
+             [dependencies]\n\
+             {dependency} = \"{}\"\n\
+             \n\
+             [workspace]\n",
+            version.name()
+        ),
+    )
+    .map_err(|err| err.to_string())?;
+    fs::write(
+        dir.join("src/lib.rs"),
+        "#![allow(dead_code)]\n\nmod style;\n",
+    )
+    .map_err(|err| err.to_string())?;
+    fs::write(dir.join("src/style.rs"), source).map_err(|err| err.to_string())?;
+
+    let output = Command::new("cargo")
+        .args(["check", "--offline", "--quiet", "--message-format", "short"])
+        .current_dir(&dir)
+        .output()
+        .map_err(|err| format!("Failed to run cargo: {err}"))?;
+
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_owned())
+    }
+}
+
+fn scratch_dir() -> PathBuf {
+    std::env::temp_dir().join("egui-themer-check")
+}
@@ -0,0 +1,545 @@
+//! Reading themes pasted from the clipboard, whatever format they're in.
+//!
+//! Pasted text can be a share link, design tokens, `Style` JSON, the JSON of
+//! one section such as `Widgets`, or Rust source from the exporter (a whole
+//! module or a single section snippet).
+
+use eframe::egui::{Color32, Style};
+use serde_json::{json, Map, Value};
+
+use crate::{
+    codegen::Section,
+    design_tokens,
+    migrate::{self, Migrated},
+    share,
+};
+
+/// What a pasted theme was recognized as.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Format {
+    ShareLink,
+    DesignTokens,
+    Json(Option<Section>),
+    Rust(Option<Section>),
+}
+
+impl Format {
+    pub fn name(self) -> String {
+        let suffix = |section: Option<Section>| match section {
+            Some(section) => format!(" ({})", section.type_name()),
+            None => String::new(),
+        };
+
+        match self {
+            Format::ShareLink => "share link".to_owned(),
+            Format::DesignTokens => "design tokens".to_owned(),
+            Format::Json(section) => format!("JSON{}", suffix(section)),
+            Format::Rust(section) => format!("Rust{}", suffix(section)),
+        }
+    }
+}
+
+/// Load pasted `text` on top of `current`. Sections replace only their own
+/// fields, and fields missing from the paste keep their current value.
+pub fn paste(text: &str, current: &Style) -> Result<(Format, Migrated), String> {
+    let text = text.trim();
+
+    if text.starts_with('{') {
+        let value: Value = serde_json::from_str(text).map_err(|e| e.to_string())?;
+
+        if design_tokens::is_design_tokens(&value) {
+            return Ok((
+                Format::DesignTokens,
+                design_tokens::import_tokens(&value, current),
+            ));
+        }
+
+        let section = json_section(&value);
+        let migrated = match section {
+            // whole styles are imported like a JSON file, defaults and all
+            None => migrate::import_style(text.as_bytes())?,
+            Some(section) => overlay(current, Some(section), value)?,
+        };
+        return Ok((Format::Json(section), migrated));
+    }
+
+    if let Some((section, value)) = rust::parse(text)? {
+        return Ok((Format::Rust(section), overlay(current, section, value)?));
+    }
+
+    share::decode(text)
+        .map(|migrated| (Format::ShareLink, migrated))
+        .map_err(|_| "the clipboard doesn't contain a theme".to_owned())
+}
+
+/// Guess which section some JSON is from by a field only it has.
+fn json_section(value: &Value) -> Option<Section> {
+    let has = |key: &str| value.get(key).is_some();
+
+    if has("visuals") || has("spacing") || has("interaction") {
+        None
+    } else if has("item_spacing") {
+        Some(Section::Spacing)
+    } else if has("resize_grab_radius_side") {
+        Some(Section::Interaction)
+    } else if has("widgets") {
+        Some(Section::Visuals)
+    } else if has("noninteractive") {
+        Some(Section::Widgets)
+    } else if has("bg_fill") && has("stroke") {
+        Some(Section::Selection)
+    } else {
+        None
+    }
+}
+
+/// Replace each field of `section` (or the whole style) in `current` that
+/// `value` has, then migrate and load the result.
+fn overlay(current: &Style, section: Option<Section>, value: Value) -> Result<Migrated, String> {
+    let Value::Object(fields) = value else {
+        return Err("expected a struct".to_owned());
+    };
+
+    let mut style = serde_json::to_value(current).map_err(|e| e.to_string())?;
+    let target = style
+        .pointer_mut(section.map_or("", Section::pointer))
+        .and_then(Value::as_object_mut)
+        .expect("sections are objects");
+    target.extend(fields);
+
+    let migrations = migrate::migrate(&mut style)?;
+    let style = serde_json::from_value(style).map_err(|e| e.to_string())?;
+
+    Ok(Migrated {
+        style,
+        migrations,
+        skipped: Vec::new(),
+    })
+}
+
+/// Just enough of a Rust expression parser to read the struct literals the
+/// exporter writes (and the usual hand edits to them) back as JSON.
+mod rust {
+    use super::*;
+
+    #[derive(Clone, Debug, PartialEq)]
+    enum Token<'a> {
+        /// An identifier or path, like `Color32::from_rgb`.
+        Path(&'a str),
+        Number(&'a str),
+        Punct(char),
+        /// The `..` of struct update syntax.
+        Rest,
+    }
+
+    /// Find the first `Style` or section struct literal in `text`.
+    pub fn parse(text: &str) -> Result<Option<(Option<Section>, Value)>, String> {
+        let tokens = tokenize(text)?;
+
+        for (i, token) in tokens.iter().enumerate() {
+            let Token::Path(path) = token else {
+                continue;
+            };
+            let name = path.rsplit("::").next().unwrap_or(path);
+            let section = match name {
+                "Style" => None,
+                name => match Section::ALL.into_iter().find(|s| s.type_name() == name) {
+                    Some(section) => Some(section),
+                    None => continue,
+                },
+            };
+
+            // skip the `-> Style {` of a function returning the struct
+            let literal = tokens.get(i + 1) == Some(&Token::Punct('{'))
+                && (i == 0 || tokens[i - 1] != Token::Punct('>'));
+            if literal {
+                let mut parser = Parser {
+                    tokens: &tokens,
+                    pos: i,
+                };
+                return Ok(Some((section, parser.value()?)));
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn tokenize(text: &str) -> Result<Vec<Token<'_>>, String> {
+        let mut tokens = Vec::new();
+        let mut rest = text;
+
+        while let Some(c) = rest.chars().next() {
+            let len = if c.is_whitespace() {
+                c.len_utf8()
+            } else if rest.starts_with("//") {
+                rest.find('\n').unwrap_or(rest.len())
+            } else if rest.starts_with("/*") {
+                rest.find("*/").map_or(rest.len(), |end| end + 2)
+            } else if c == '#' {
+                // attributes hold nothing we need
+                attribute_len(rest)?
+            } else if let Some(len) = string_len(rest) {
+                // nor do strings, like the font names in `fonts()`
+                len?
+            } else if rest.starts_with("..") {
+                tokens.push(Token::Rest);
+                2
+            } else if c.is_ascii_alphabetic() || c == '_' {
+                let len = path_len(rest);
+                tokens.push(Token::Path(&rest[..len]));
+                len
+            } else if c.is_ascii_digit()
+                || (c == '-' && rest[1..].starts_with(|c: char| c.is_ascii_digit()))
+            {
+                let len = number_len(rest);
+                tokens.push(Token::Number(&rest[..len]));
+                len
+            } else {
+                tokens.push(Token::Punct(c));
+                c.len_utf8()
+            };
+            rest = &rest[len..];
+        }
+
+        Ok(tokens)
+    }
+
+    /// The length of the attribute `text` starts with, up to the `]` closing
+    /// its opening `[`, past any brackets nested or quoted inside it.
+    fn attribute_len(text: &str) -> Result<usize, &'static str> {
+        let mut depth = 0;
+        let mut len = 0;
+        while let Some(c) = text[len..].chars().next() {
+            if let Some(string) = string_len(&text[len..]) {
+                len += string?;
+                continue;
+            }
+            if c.is_ascii_alphabetic() || c == '_' {
+                len += path_len(&text[len..]);
+                continue;
+            }
+
+            len += c.len_utf8();
+            match c {
+                '[' => depth += 1,
+                ']' if depth == 1 => return Ok(len),
+                ']' => depth -= 1,
+                _ => {}
+            }
+        }
+        Err("unclosed attribute")
+    }
+
+    /// The length of the string literal `text` starts with, if it starts with
+    /// one. Plain strings skip escaped quotes, raw strings end at a quote
+    /// followed by as many `#`s as they opened with.
+    fn string_len(text: &str) -> Option<Result<usize, &'static str>> {
+        let unprefixed = text.strip_prefix('b').unwrap_or(text);
+        let prefix = text.len() - unprefixed.len();
+
+        if let Some(raw) = unprefixed.strip_prefix('r') {
+            let hashes = raw.len() - raw.trim_start_matches('#').len();
+            let body = raw[hashes..].strip_prefix('"')?;
+            let end = format!("\"{}", "#".repeat(hashes));
+            let start = prefix + 1 + hashes + 1;
+            return Some(
+                body.find(&end)
+                    .map(|at| start + at + end.len())
+                    .ok_or("unclosed string"),
+            );
+        }
+
+        let body = unprefixed.strip_prefix('"')?;
+        let mut chars = body.char_indices();
+        while let Some((at, c)) = chars.next() {
+            match c {
+                '\\' => {
+                    chars.next();
+                }
+                '"' => return Some(Ok(prefix + 1 + at + 1)),
+                _ => {}
+            }
+        }
+        Some(Err("unclosed string"))
+    }
+
+    /// The length of the identifier or `::` separated path `text` starts with.
+    fn path_len(text: &str) -> usize {
+        let ident = |text: &str| {
+            text.find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                .unwrap_or(text.len())
+        };
+
+        let mut len = ident(text);
+        while text[len..].starts_with("::")
+            && text[len + 2..].starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        {
+            len += 2 + ident(&text[len + 2..]);
+        }
+        len
+    }
+
+    /// The length of the number literal `text` starts with, including any
+    /// sign, exponent and type suffix.
+    fn number_len(text: &str) -> usize {
+        let bytes = text.as_bytes();
+        let mut len = 1;
+        while let Some(&c) = bytes.get(len) {
+            let exponent_sign = matches!(c, b'-' | b'+') && matches!(bytes[len - 1], b'e' | b'E');
+            let fraction = c == b'.' && bytes.get(len + 1) != Some(&b'.');
+            if c.is_ascii_alphanumeric() || c == b'_' || fraction || exponent_sign {
+                len += 1;
+            } else {
+                break;
+            }
+        }
+        len
+    }
+
+    struct Parser<'a> {
+        tokens: &'a [Token<'a>],
+        pos: usize,
+    }
+
+    impl<'a> Parser<'a> {
+        fn next(&mut self) -> Option<Token<'a>> {
+            let token = self.tokens.get(self.pos).cloned();
+            self.pos += 1;
+            token
+        }
+
+        fn peek(&self) -> Option<&Token<'a>> {
+            self.tokens.get(self.pos)
+        }
+
+        fn expect(&mut self, punct: char) -> Result<(), String> {
+            match self.next() {
+                Some(Token::Punct(c)) if c == punct => Ok(()),
+                other => Err(format!("expected `{punct}`, found {other:?}")),
+            }
+        }
+
+        /// Skip `punct` if it's next.
+        fn eat(&mut self, punct: char) -> bool {
+            let found = self.peek() == Some(&Token::Punct(punct));
+            if found {
+                self.pos += 1;
+            }
+            found
+        }
+
+        fn value(&mut self) -> Result<Value, String> {
+            match self.next() {
+                Some(Token::Number(number)) => number_value(number),
+                Some(Token::Punct('[')) => Ok(Value::Array(self.list(']')?)),
+                Some(Token::Path("true")) => Ok(Value::Bool(true)),
+                Some(Token::Path("false")) => Ok(Value::Bool(false)),
+                Some(Token::Path("None")) => Ok(Value::Null),
+                Some(Token::Path(path)) if self.eat('{') => {
+                    let fields = self.fields()?;
+                    Ok(enum_variant(path, Value::Object(fields)))
+                }
+                Some(Token::Path(path)) if self.eat('(') => {
+                    let args = self.list(')')?;
+                    call(path, args)
+                }
+                Some(Token::Path(path)) => constant(path),
+                other => Err(format!("expected a value, found {other:?}")),
+            }
+        }
+
+        /// Comma separated values up to `close`.
+        fn list(&mut self, close: char) -> Result<Vec<Value>, String> {
+            let mut values = Vec::new();
+            while !self.eat(close) {
+                values.push(self.value()?);
+                if !self.eat(',') {
+                    self.expect(close)?;
+                    break;
+                }
+            }
+            Ok(values)
+        }
+
+        /// The fields of a struct literal up to its closing brace.
+        fn fields(&mut self) -> Result<Map<String, Value>, String> {
+            let mut fields = Map::new();
+            loop {
+                match self.next() {
+                    Some(Token::Punct('}')) => return Ok(fields),
+                    Some(Token::Rest) => {
+                        // the rest keeps its current value, so skip the
+                        // `Default::default()` or whatever else it is
+                        self.skip_expression();
+                    }
+                    Some(Token::Path(name)) => {
+                        self.expect(':')?;
+                        fields.insert(name.to_owned(), self.value()?);
+                        if !self.eat(',') {
+                            self.expect('}')?;
+                            return Ok(fields);
+                        }
+                    }
+                    other => return Err(format!("expected a field, found {other:?}")),
+                }
+            }
+        }
+
+        /// Skip to the `,` or `}` ending the current expression.
+        fn skip_expression(&mut self) {
+            let mut depth = 0usize;
+            while let Some(token) = self.peek() {
+                match token {
+                    Token::Punct('{' | '(' | '[') => depth += 1,
+                    Token::Punct('}' | ')' | ']') if depth == 0 => return,
+                    Token::Punct('}' | ')' | ']') => depth -= 1,
+                    Token::Punct(',') if depth == 0 => {
+                        self.pos += 1;
+                        return;
+                    }
+                    _ => {}
+                }
+                self.pos += 1;
+            }
+        }
+    }
+
+    fn number_value(number: &str) -> Result<Value, String> {
+        let digits = number
+            .trim_end_matches("f32")
+            .trim_end_matches("f64")
+            .trim_end_matches("u8")
+            .replace('_', "");
+        let error = || format!("invalid number `{number}`");
+
+        if digits.contains(['.', 'e', 'E']) {
+            let float: f64 = digits.parse().map_err(|_| error())?;
+            serde_json::Number::from_f64(float)
+                .map(Value::Number)
+                .ok_or_else(error)
+        } else {
+            digits.parse::<i64>().map(Value::from).map_err(|_| error())
+        }
+    }
+
+    /// `HandleShape::Rect { .. }` is serialized as `{"Rect": {..}}`, while
+    /// plain structs (even qualified ones like `egui::Stroke`) are objects.
+    fn enum_variant(path: &str, fields: Value) -> Value {
+        let mut segments = path.rsplit("::");
+        let name = segments.next().unwrap_or(path);
+        match segments.next() {
+            Some(parent) if parent.starts_with(|c: char| c.is_ascii_uppercase()) => {
+                json!({ name: fields })
+            }
+            _ => fields,
+        }
+    }
+
+    fn call(path: &str, args: Vec<Value>) -> Result<Value, String> {
+        let float = |i: usize| {
+            args.get(i)
+                .and_then(Value::as_f64)
+                .ok_or_else(|| format!("`{path}` expects a number as argument {}", i + 1))
+        };
+        let byte = |i: usize| {
+            args.get(i)
+                .and_then(Value::as_u64)
+                .and_then(|n| u8::try_from(n).ok())
+                .ok_or_else(|| format!("`{path}` expects a u8 as argument {}", i + 1))
+        };
+        let color = |color: Color32| json!(color.to_array());
+
+        let name = path.rsplit("::").take(2).collect::<Vec<_>>();
+        Ok(match name.as_slice() {
+            ["Some", ..] => args
+                .into_iter()
+                .next()
+                .ok_or_else(|| format!("`{path}` expects a value"))?,
+            ["from_rgba_premultiplied", "Color32"] => {
+                json!([byte(0)?, byte(1)?, byte(2)?, byte(3)?])
+            }
+            ["from_rgba_unmultiplied", "Color32"] => color(Color32::from_rgba_unmultiplied(
+                byte(0)?,
+                byte(1)?,
+                byte(2)?,
+                byte(3)?,
+            )),
+            ["from_rgb", "Color32"] => color(Color32::from_rgb(byte(0)?, byte(1)?, byte(2)?)),
+            ["from_gray", "Color32"] => color(Color32::from_gray(byte(0)?)),
+            ["from_black_alpha", "Color32"] => color(Color32::from_black_alpha(byte(0)?)),
+            ["from_white_alpha", "Color32"] => color(Color32::from_white_alpha(byte(0)?)),
+            ["new", "Vec2"] | ["vec2", ..] => json!({ "x": float(0)?, "y": float(1)? }),
+            ["splat", "Vec2"] => json!({ "x": float(0)?, "y": float(0)? }),
+            ["new", "Stroke"] => {
+                let color = args
+                    .get(1)
+                    .cloned()
+                    .ok_or_else(|| format!("`{path}` expects a color as argument 2"))?;
+                json!({ "width": float(0)?, "color": color })
+            }
+            ["same", "Rounding"] => {
+                let r = float(0)?;
+                json!({ "nw": r, "ne": r, "sw": r, "se": r })
+            }
+            ["same", "Margin"] => {
+                let m = float(0)?;
+                json!({ "left": m, "right": m, "top": m, "bottom": m })
+            }
+            ["symmetric", "Margin"] => {
+                let (x, y) = (float(0)?, float(1)?);
+                json!({ "left": x, "right": x, "top": y, "bottom": y })
+            }
+            _ => return Err(format!("unsupported function `{path}`")),
+        })
+    }
+
+    /// Named constants, falling back to unit enum variants like `CursorIcon::Grab`.
+    fn constant(path: &str) -> Result<Value, String> {
+        let zero_vec = json!({ "x": 0.0, "y": 0.0 });
+        let name = path.rsplit("::").take(2).collect::<Vec<_>>();
+
+        Ok(match name.as_slice() {
+            ["ZERO", "Vec2"] => zero_vec,
+            ["ZERO", "Rounding"] => json!({ "nw": 0.0, "ne": 0.0, "sw": 0.0, "se": 0.0 }),
+            ["ZERO", "Margin"] => json!({ "left": 0.0, "right": 0.0, "top": 0.0, "bottom": 0.0 }),
+            ["NONE", "Stroke"] => json!({ "width": 0.0, "color": [0, 0, 0, 0] }),
+            ["NONE", "Shadow"] => json!({
+                "offset": zero_vec,
+                "blur": 0.0,
+                "spread": 0.0,
+                "color": [0, 0, 0, 0],
+            }),
+            [constant, "Color32"] => json!(color_constant(constant)
+                .ok_or_else(|| format!("unknown color `{path}`"))?
+                .to_array()),
+            [variant, ..] => Value::String((*variant).to_owned()),
+            [] => unreachable!("rsplit yields at least one segment"),
+        })
+    }
+
+    fn color_constant(name: &str) -> Option<Color32> {
+        Some(match name {
+            "TRANSPARENT" => Color32::TRANSPARENT,
+            "BLACK" => Color32::BLACK,
+            "DARK_GRAY" => Color32::DARK_GRAY,
+            "GRAY" => Color32::GRAY,
+            "LIGHT_GRAY" => Color32::LIGHT_GRAY,
+            "WHITE" => Color32::WHITE,
+            "BROWN" => Color32::BROWN,
+            "DARK_RED" => Color32::DARK_RED,
+            "RED" => Color32::RED,
+            "LIGHT_RED" => Color32::LIGHT_RED,
+            "YELLOW" => Color32::YELLOW,
+            "LIGHT_YELLOW" => Color32::LIGHT_YELLOW,
+            "KHAKI" => Color32::KHAKI,
+            "DARK_GREEN" => Color32::DARK_GREEN,
+            "GREEN" => Color32::GREEN,
+            "LIGHT_GREEN" => Color32::LIGHT_GREEN,
+            "DARK_BLUE" => Color32::DARK_BLUE,
+            "BLUE" => Color32::BLUE,
+            "LIGHT_BLUE" => Color32::LIGHT_BLUE,
+            "GOLD" => Color32::GOLD,
+            _ => return None,
+        })
+    }
+}
@@ -0,0 +1,452 @@
+use eframe::egui::{Style, TextWrapMode};
+use handlebars::{handlebars_helper, Handlebars, JsonValue};
+
+#[cfg(not(target_arch = "wasm32"))]
+use rust_format::{Formatter, RustFmt};
+
+/// The full module, then the partials it's built from.
+const TEMPLATES: [(&str, &str); 8] = [
+    ("style", include_str!("templates/style.rs.hbs")),
+    ("snippet", include_str!("templates/snippet.rs.hbs")),
+    ("imports", include_str!("templates/imports.rs.hbs")),
+    ("spacing", include_str!("templates/spacing.rs.hbs")),
+    ("interaction", include_str!("templates/interaction.rs.hbs")),
+    ("visuals", include_str!("templates/visuals.rs.hbs")),
+    ("widgets", include_str!("templates/widgets.rs.hbs")),
+    ("selection", include_str!("templates/selection.rs.hbs")),
+];
+
+/// egui releases the Rust exporter can target.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum EguiVersion {
+    V0_26,
+    V0_27,
+    #[default]
+    V0_28,
+    V0_29,
+}
+
+impl EguiVersion {
+    pub const ALL: [Self; 4] = [Self::V0_26, Self::V0_27, Self::V0_28, Self::V0_29];
+
+    /// The version as written in a `Cargo.toml` dependency.
+    pub fn name(self) -> &'static str {
+        match self {
+            EguiVersion::V0_26 => "0.26",
+            EguiVersion::V0_27 => "0.27",
+            EguiVersion::V0_28 => "0.28",
+            EguiVersion::V0_29 => "0.29",
+        }
+    }
+
+    /// Describe each value in `style` that this version has no field for,
+    /// and how it's exported instead.
+    pub fn warnings(self, style: &Style) -> Vec<String> {
+        let default = Style::default();
+        let mut warnings = Vec::new();
+        let mut left_out = |field: &str, changed: bool| {
+            if changed {
+                warnings.push(format!(
+                    "{field} doesn't exist in egui {} and is left out",
+                    self.name()
+                ));
+            }
+        };
+
+        if self < EguiVersion::V0_27 {
+            left_out(
+                "url_in_tooltip",
+                style.url_in_tooltip != default.url_in_tooltip,
+            );
+            left_out(
+                "spacing.slider_rail_height",
+                style.spacing.slider_rail_height != default.spacing.slider_rail_height,
+            );
+            left_out(
+                "spacing.menu_spacing",
+                style.spacing.menu_spacing != default.spacing.menu_spacing,
+            );
+            left_out(
+                "interaction.interact_radius",
+                style.interaction.interact_radius != default.interaction.interact_radius,
+            );
+        }
+
+        if self < EguiVersion::V0_28 {
+            let cursor = &style.visuals.text_cursor;
+            let default_cursor = &default.visuals.text_cursor;
+
+            left_out(
+                "spacing.default_area_size",
+                style.spacing.default_area_size != default.spacing.default_area_size,
+            );
+            left_out(
+                "interaction.tooltip_grace_time",
+                style.interaction.tooltip_grace_time != default.interaction.tooltip_grace_time,
+            );
+            left_out(
+                "visuals.text_cursor blinking",
+                cursor.blink != default_cursor.blink
+                    || cursor.on_duration != default_cursor.on_duration
+                    || cursor.off_duration != default_cursor.off_duration,
+            );
+        }
+
+        if self < EguiVersion::V0_27 {
+            for (field, shadow) in [
+                ("visuals.window_shadow", style.visuals.window_shadow),
+                ("visuals.popup_shadow", style.visuals.popup_shadow),
+            ] {
+                if shadow.offset != Default::default() || shadow.spread != 0.0 {
+                    warnings.push(format!(
+                        "{field} offset and spread don't exist in egui {}, only blur is exported (as extrusion)",
+                        self.name()
+                    ));
+                }
+            }
+        }
+
+        if self < EguiVersion::V0_28 && style.wrap_mode == Some(TextWrapMode::Truncate) {
+            warnings.push(format!(
+                "wrap_mode Truncate doesn't exist in egui {}, it's exported as wrap: Some(false)",
+                self.name()
+            ));
+        }
+
+        warnings
+    }
+}
+
+/// What the generated Rust module should contain besides `pub fn style() -> Style`.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct SourceOptions {
+    /// Import egui through `eframe::egui`. Ignored for crates, which have an
+    /// `eframe` feature instead.
+    pub eframe: bool,
+    pub version: EguiVersion,
+    /// Emit `pub static STYLE: LazyLock<Style>` (needs Rust 1.80).
+    pub lazy_static: bool,
+    /// Emit `pub fn style_arc() -> Arc<Style>`.
+    pub arc: bool,
+    /// Emit `pub fn apply(ctx: &Context)`, which sets the style, the fonts
+    /// and this `pixels_per_point`.
+    pub apply: Option<f32>,
+}
+
+/// Render `style` as a Rust module exposing `pub fn style() -> Style`, plus
+/// whatever else `options` asks for.
+pub fn generate_source(style: &Style, options: &SourceOptions) -> Result<String, String> {
+    let eframe = if options.eframe {
+        EframeImport::Always
+    } else {
+        EframeImport::Never
+    };
+
+    render("style", style, eframe, options, None)
+}
+
+/// Render `style` as a complete library crate named `name`, returning the
+/// path and contents of each file relative to the crate root.
+///
+/// The crate exposes `style()` and `fonts()`, an optional `eframe` feature,
+/// and a `demo` example showing the egui demo with the theme applied.
+pub fn generate_crate(
+    style: &Style,
+    name: &str,
+    options: &SourceOptions,
+) -> Result<Vec<(&'static str, String)>, String> {
+    if !is_crate_name(name) {
+        return Err(format!(
+            "\"{name}\" isn't a valid crate name, use letters, digits, '-' and '_'"
+        ));
+    }
+
+    let ident = name.replace('-', "_");
+    // `run_native` expects the app creator to return a `Result` since 0.28
+    let app = if options.version >= EguiVersion::V0_28 {
+        "Ok(Box::new(Demo::default()))"
+    } else {
+        "Box::new(Demo::default())"
+    };
+
+    let manifest = format!(
+        r#"[package]
+name = "{name}"
+version = "0.1.0"
+edition = "2021"
+
+[features]
+eframe = ["dep:eframe"]
+
+[dependencies]
+egui = "{version}"
+eframe = {{ version = "{version}", optional = true }}
+
+[dev-dependencies]
+egui_demo_lib = "{version}"
+
+[[example]]
+name = "demo"
+required-features = ["eframe"]
+"#,
+        version = options.version.name()
+    );
+
+    // everything the style module defines, which is private
+    let exports = [
+        Some("fonts"),
+        Some("style"),
+        options.lazy_static.then_some("STYLE"),
+        options.arc.then_some("style_arc"),
+        options.apply.is_some().then_some("apply"),
+    ];
+    let exports = exports.into_iter().flatten().collect::<Vec<_>>().join(", ");
+    let lib = format!(
+        r#"//! An egui theme generated by egui-themer (https://github.com/grantshandy/egui-themer).
+
+mod style;
+
+pub use style::{{{exports}}};
+"#
+    );
+
+    let example = format!(
+        r#"use eframe::egui::Context;
+use egui_demo_lib::DemoWindows;
+
+fn main() -> eframe::Result<()> {{
+    eframe::run_native(
+        "{name}",
+        eframe::NativeOptions::default(),
+        Box::new(|cc| {{
+            cc.egui_ctx.set_style({ident}::style());
+            cc.egui_ctx.set_fonts({ident}::fonts());
+            {app}
+        }}),
+    )
+}}
+
+#[derive(Default)]
+struct Demo(DemoWindows);
+
+impl eframe::App for Demo {{
+    fn update(&mut self, ctx: &Context, _frame: &mut eframe::Frame) {{
+        self.0.ui(ctx);
+    }}
+}}
+"#
+    );
+
+    Ok(vec![
+        ("Cargo.toml", manifest),
+        ("src/lib.rs", lib),
+        (
+            "src/style.rs",
+            render("style", style, EframeImport::Feature, options, None)?,
+        ),
+        ("examples/demo.rs", example),
+    ])
+}
+
+/// Whether `name` can be used as a Cargo package name.
+pub fn is_crate_name(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_alphabetic())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+/// A part of `Style` that can be exported on its own as a Rust snippet.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Section {
+    Spacing,
+    Interaction,
+    Visuals,
+    Widgets,
+    Selection,
+}
+
+impl Section {
+    pub const ALL: [Self; 5] = [
+        Self::Spacing,
+        Self::Interaction,
+        Self::Visuals,
+        Self::Widgets,
+        Self::Selection,
+    ];
+
+    /// The name of both the section's partial template and its snippet function.
+    pub fn name(self) -> &'static str {
+        match self {
+            Section::Spacing => "spacing",
+            Section::Interaction => "interaction",
+            Section::Visuals => "visuals",
+            Section::Widgets => "widgets",
+            Section::Selection => "selection",
+        }
+    }
+
+    /// The egui type of the section.
+    pub fn type_name(self) -> &'static str {
+        match self {
+            Section::Spacing => "Spacing",
+            Section::Interaction => "Interaction",
+            Section::Visuals => "Visuals",
+            Section::Widgets => "Widgets",
+            Section::Selection => "Selection",
+        }
+    }
+
+    /// Where the section sits in `Style` JSON.
+    pub fn pointer(self) -> &'static str {
+        match self {
+            Section::Spacing => "/spacing",
+            Section::Interaction => "/interaction",
+            Section::Visuals => "/visuals",
+            Section::Widgets => "/visuals/widgets",
+            Section::Selection => "/visuals/selection",
+        }
+    }
+
+    /// Set this section of `to` to the one in `from`.
+    pub fn copy(self, from: &Style, to: &mut Style) {
+        match self {
+            Section::Spacing => to.spacing = from.spacing.clone(),
+            Section::Interaction => to.interaction = from.interaction.clone(),
+            Section::Visuals => to.visuals = from.visuals.clone(),
+            Section::Widgets => to.visuals.widgets = from.visuals.widgets.clone(),
+            Section::Selection => to.visuals.selection = from.visuals.selection,
+        }
+    }
+}
+
+/// Render one section of `style` as a Rust function returning it, e.g.
+/// `pub fn widgets() -> Widgets`.
+pub fn generate_snippet(
+    style: &Style,
+    section: Section,
+    options: &SourceOptions,
+) -> Result<String, String> {
+    let eframe = if options.eframe {
+        EframeImport::Always
+    } else {
+        EframeImport::Never
+    };
+
+    render("snippet", style, eframe, options, Some(section))
+}
+
+/// How the generated module gets hold of egui.
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum EframeImport {
+    Never,
+    Always,
+    /// Through eframe when the generated crate's `eframe` feature is enabled.
+    Feature,
+}
+
+fn render(
+    template: &str,
+    style: &Style,
+    eframe: EframeImport,
+    options: &SourceOptions,
+    section: Option<Section>,
+) -> Result<String, String> {
+    let version = options.version;
+
+    let mut reg = Handlebars::new();
+
+    for (name, source) in TEMPLATES {
+        reg.register_template_string(name, source)
+            .map_err(|err| err.to_string())?;
+    }
+
+    reg.register_helper("vec2", Box::new(vec2));
+    reg.register_helper("stroke", Box::new(stroke));
+    reg.register_helper("rounding", Box::new(rounding));
+    reg.register_helper("color32", Box::new(color32));
+    reg.register_helper("widgetvisuals", Box::new(widgetvisuals));
+    reg.register_helper("handleshape", Box::new(handleshape));
+
+    let res = reg
+        .render(
+            template,
+            &serde_json::json!({
+                "section": section.map(Section::name),
+                "section_type": section.map(Section::type_name),
+                "eframe": eframe == EframeImport::Always,
+                "eframe_feature": eframe == EframeImport::Feature,
+                "lazy_static": options.lazy_static,
+                "arc": options.arc,
+                "apply": options.apply.is_some(),
+                "pixels_per_point": options.apply.map(|ppp| format!("{ppp:?}")),
+                // crates always get `fonts()`, modules only when `apply` uses it
+                "fonts": eframe == EframeImport::Feature || options.apply.is_some(),
+                "style": style,
+                "since_0_27": version >= EguiVersion::V0_27,
+                "since_0_28": version >= EguiVersion::V0_28,
+                // before 0.28 there's only the boolean `wrap`
+                "wrap": style.wrap_mode.map(|mode| (mode == TextWrapMode::Wrap).to_string()),
+            }),
+        )
+        .map_err(|err| err.to_string())?;
+
+    #[cfg(not(target_arch = "wasm32"))]
+    let res = RustFmt::default()
+        .format_str(res)
+        .map_err(|err| err.to_string())?;
+
+    Ok(res)
+}
+
+handlebars_helper!(vec2: |value: JsonValue| format!("Vec2 {{ x: {}, y: {}}}", &value["x"], &value["y"]));
+handlebars_helper!(stroke: |value: JsonValue| gen_stroke(&value));
+handlebars_helper!(rounding: |value: JsonValue| gen_rounding(&value));
+handlebars_helper!(color32: |color: JsonValue| gen_color32(&color));
+handlebars_helper!(widgetvisuals: |value: JsonValue| {
+    format!("WidgetVisuals {{
+        bg_fill: {},
+        weak_bg_fill: {},
+        bg_stroke: {},
+        rounding: {},
+        fg_stroke: {},
+        expansion: {},
+    }}",
+        gen_color32(&value["bg_fill"]),
+        gen_color32(&value["weak_bg_fill"]),
+        gen_stroke(&value["bg_stroke"]),
+        gen_rounding(&value["rounding"]),
+        gen_stroke(&value["fg_stroke"]),
+        value["expansion"]
+    )
+});
+
+handlebars_helper!(handleshape: |value: JsonValue| match &value["Rect"] {
+    JsonValue::Null => "HandleShape::Circle".to_owned(),
+    rect => format!("HandleShape::Rect {{ aspect_ratio: {} }}", rect["aspect_ratio"]),
+});
+
+fn gen_color32(value: &JsonValue) -> String {
+    format!(
+        "Color32::from_rgba_premultiplied({}, {}, {}, {})",
+        value[0], value[1], value[2], value[3]
+    )
+}
+
+fn gen_stroke(value: &JsonValue) -> String {
+    format!(
+        "Stroke {{
+            width: {},
+            color: {},
+        }}",
+        value["width"],
+        gen_color32(&value["color"]),
+    )
+}
+
+fn gen_rounding(value: &JsonValue) -> String {
+    format!(
+        "Rounding {{ nw: {}, ne: {}, sw: {}, se: {} }}",
+        value["nw"], value["ne"], value["sw"], value["se"]
+    )
+}
@@ -0,0 +1,153 @@
+//! CSS custom properties mirroring a `Style`, for web UIs that should match it.
+
+use std::fmt::Write;
+
+use eframe::egui::Style;
+
+use crate::tokens::{self, Token};
+
+/// Render `style` as `--egui-*` custom properties on `:root`, followed by a
+/// sample stylesheet for panels, windows, buttons and links using them.
+///
+/// Property names are the token's field path with `.` and `_` replaced by
+/// `-`, e.g. `visuals.window_fill` becomes `--egui-visuals-window-fill`.
+pub fn generate_css(style: &Style) -> String {
+    let mut css = String::from(
+        "/* Generated by egui-themer (https://github.com/grantshandy/egui-themer). */\n\n:root {\n",
+    );
+
+    tokens::visit(&mut style.clone(), &mut |path, token| {
+        let name = format!("--egui-{}", path.replace(['.', '_'], "-"));
+        let mut property = |suffix: &str, value: String| {
+            _ = writeln!(css, "    {name}{suffix}: {value};");
+        };
+
+        match token {
+            Token::Color(color) => property("", tokens::hex_color(*color)),
+            Token::Dimension(value) => property("", px(*value)),
+            Token::Vec2(vec) => {
+                property("-x", px(vec.x));
+                property("-y", px(vec.y));
+            }
+            Token::Margin(margin) => {
+                // same order as the `padding` shorthand
+                property(
+                    "",
+                    format!(
+                        "{} {} {} {}",
+                        px(margin.top),
+                        px(margin.right),
+                        px(margin.bottom),
+                        px(margin.left)
+                    ),
+                );
+            }
+            Token::Rounding(rounding) => {
+                // same order as the `border-radius` shorthand
+                property(
+                    "",
+                    format!(
+                        "{} {} {} {}",
+                        px(rounding.nw),
+                        px(rounding.ne),
+                        px(rounding.se),
+                        px(rounding.sw)
+                    ),
+                );
+            }
+            Token::Stroke(stroke) => {
+                property(
+                    "",
+                    format!(
+                        "{} solid {}",
+                        px(stroke.width),
+                        tokens::hex_color(stroke.color)
+                    ),
+                );
+                property("-width", px(stroke.width));
+                property("-color", tokens::hex_color(stroke.color));
+            }
+            Token::Shadow(shadow) => {
+                // same order as the `box-shadow` shorthand
+                property(
+                    "",
+                    format!(
+                        "{} {} {} {} {}",
+                        px(shadow.offset.x),
+                        px(shadow.offset.y),
+                        px(shadow.blur),
+                        px(shadow.spread),
+                        tokens::hex_color(shadow.color)
+                    ),
+                );
+            }
+        }
+    });
+
+    css.push_str("}\n\n");
+    css.push_str(SAMPLE_STYLESHEET);
+    css
+}
+
+fn px(value: f32) -> String {
+    format!("{value}px")
+}
+
+const SAMPLE_STYLESHEET: &str = "\
+/* A starting point for web components matching the egui theme. */
+
+.egui-panel {
+    background: var(--egui-visuals-panel-fill);
+    color: var(--egui-visuals-widgets-noninteractive-fg-stroke-color);
+}
+
+.egui-window {
+    background: var(--egui-visuals-window-fill);
+    color: var(--egui-visuals-widgets-noninteractive-fg-stroke-color);
+    border: var(--egui-visuals-window-stroke);
+    border-radius: var(--egui-visuals-window-rounding);
+    box-shadow: var(--egui-visuals-window-shadow);
+    padding: var(--egui-spacing-window-margin);
+}
+
+.egui-button {
+    background: var(--egui-visuals-widgets-inactive-weak-bg-fill);
+    color: var(--egui-visuals-widgets-inactive-fg-stroke-color);
+    border: var(--egui-visuals-widgets-inactive-bg-stroke);
+    border-radius: var(--egui-visuals-widgets-inactive-rounding);
+    padding: var(--egui-spacing-button-padding-y) var(--egui-spacing-button-padding-x);
+    min-height: var(--egui-spacing-interact-size-y);
+}
+
+.egui-button:hover {
+    background: var(--egui-visuals-widgets-hovered-weak-bg-fill);
+    color: var(--egui-visuals-widgets-hovered-fg-stroke-color);
+    border: var(--egui-visuals-widgets-hovered-bg-stroke);
+    border-radius: var(--egui-visuals-widgets-hovered-rounding);
+}
+
+.egui-button:active {
+    background: var(--egui-visuals-widgets-active-weak-bg-fill);
+    color: var(--egui-visuals-widgets-active-fg-stroke-color);
+    border: var(--egui-visuals-widgets-active-bg-stroke);
+    border-radius: var(--egui-visuals-widgets-active-rounding);
+}
+
+.egui-button:disabled {
+    background: var(--egui-visuals-widgets-noninteractive-weak-bg-fill);
+    color: var(--egui-visuals-widgets-noninteractive-fg-stroke-color);
+    border: var(--egui-visuals-widgets-noninteractive-bg-stroke);
+}
+
+.egui-panel a {
+    color: var(--egui-visuals-hyperlink-color);
+}
+
+.egui-panel code {
+    background: var(--egui-visuals-code-bg-color);
+}
+
+.egui-panel ::selection {
+    background: var(--egui-visuals-selection-bg-fill);
+}
+";
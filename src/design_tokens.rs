@@ -0,0 +1,306 @@
+//! W3C Design Tokens (DTCG) JSON, as used by Figma Tokens and friends.
+//!
+//! Tokens are grouped by their `Style` field path, so `visuals.window_fill`
+//! is the token `{"visuals": {"window_fill": {"$type": "color", ...}}}`.
+//! Vectors, margins and roundings become groups of dimension tokens, strokes
+//! are `border` tokens and shadows are `shadow` tokens. Colors are written
+//! unmultiplied, as `#rrggbb` or `#rrggbbaa`.
+
+use eframe::{
+    egui::{Margin, Rounding, Stroke, Style},
+    epaint::{Color32, Shadow},
+};
+use serde_json::{json, Map, Value};
+
+use crate::{
+    migrate::{Migrated, SkippedField},
+    tokens::{self, Token},
+};
+
+/// How many aliases are followed before giving up on a reference cycle.
+const MAX_ALIAS_DEPTH: usize = 16;
+
+/// Render every token in `style` as a design token document.
+pub fn generate_tokens(style: &Style) -> Value {
+    let mut root = Map::new();
+
+    tokens::visit(&mut style.clone(), &mut |path, token| {
+        let value = match token {
+            Token::Color(color) => color_token(*color),
+            Token::Dimension(value) => dimension(*value),
+            Token::Vec2(vec) => json!({ "x": dimension(vec.x), "y": dimension(vec.y) }),
+            Token::Margin(margin) => json!({
+                "left": dimension(margin.left),
+                "right": dimension(margin.right),
+                "top": dimension(margin.top),
+                "bottom": dimension(margin.bottom),
+            }),
+            Token::Rounding(rounding) => json!({
+                "nw": dimension(rounding.nw),
+                "ne": dimension(rounding.ne),
+                "sw": dimension(rounding.sw),
+                "se": dimension(rounding.se),
+            }),
+            Token::Stroke(stroke) => typed(
+                "border",
+                json!({
+                    "color": color_value(stroke.color),
+                    "width": dimension_value(stroke.width),
+                    "style": "solid",
+                }),
+            ),
+            Token::Shadow(shadow) => typed(
+                "shadow",
+                json!({
+                    "color": color_value(shadow.color),
+                    "offsetX": dimension_value(shadow.offset.x),
+                    "offsetY": dimension_value(shadow.offset.y),
+                    "blur": dimension_value(shadow.blur),
+                    "spread": dimension_value(shadow.spread),
+                }),
+            ),
+        };
+
+        let mut group = &mut root;
+        let mut segments = path.split('.').peekable();
+        while let Some(segment) = segments.next() {
+            if segments.peek().is_none() {
+                group.insert(segment.to_owned(), value);
+                break;
+            }
+            group = group
+                .entry(segment)
+                .or_insert_with(|| Value::Object(Map::new()))
+                .as_object_mut()
+                .expect("token groups are objects");
+        }
+    });
+
+    Value::Object(root)
+}
+
+/// Whether `value` looks like a design token document rather than `Style` JSON.
+pub fn is_design_tokens(value: &Value) -> bool {
+    match value {
+        Value::Object(object) => {
+            object.contains_key("$value") || object.values().any(is_design_tokens)
+        }
+        _ => false,
+    }
+}
+
+/// Read the tokens in `document` on top of `current`. Tokens missing from
+/// the document keep their current value, malformed ones are listed in
+/// [`Migrated::skipped`].
+pub fn import_tokens(document: &Value, current: &Style) -> Migrated {
+    let mut style = current.clone();
+    let mut skipped = Vec::new();
+
+    tokens::visit(&mut style, &mut |path, token| {
+        let Some(node) = lookup(document, path) else {
+            return;
+        };
+
+        if let Err(reason) = read_token(document, node, token) {
+            skipped.push(SkippedField {
+                path: path.to_owned(),
+                reason,
+            });
+        }
+    });
+
+    Migrated {
+        style,
+        migrations: Vec::new(),
+        skipped,
+    }
+}
+
+fn read_token(document: &Value, node: &Value, token: Token) -> Result<(), String> {
+    // a token in the group of a vector, margin or rounding
+    let dimension = |name: &str| {
+        let token = node
+            .get(name)
+            .ok_or_else(|| format!("missing the {name} token"))?;
+        parse_dimension(resolve(document, token)?)
+    };
+    // a property of a composite border or shadow value
+    let value = |name: &str| {
+        resolve(document, node)?
+            .get(name)
+            .ok_or_else(|| format!("missing {name} in the token value"))
+            .and_then(|value| resolve_value(document, value))
+    };
+
+    match token {
+        Token::Color(color) => {
+            let value = resolve(document, node)?;
+            let parsed = parse_color(value)?;
+            // only trust the saved original while `$value` hasn't been edited
+            *color = premultiplied(node)
+                .filter(|original| {
+                    value.as_str().map(str::to_lowercase) == Some(tokens::hex_color(*original))
+                })
+                .unwrap_or(parsed);
+        }
+        Token::Dimension(value) => *value = parse_dimension(resolve(document, node)?)?,
+        Token::Vec2(vec) => *vec = [dimension("x")?, dimension("y")?].into(),
+        Token::Margin(margin) => {
+            *margin = Margin {
+                left: dimension("left")?,
+                right: dimension("right")?,
+                top: dimension("top")?,
+                bottom: dimension("bottom")?,
+            };
+        }
+        Token::Rounding(rounding) => {
+            *rounding = Rounding {
+                nw: dimension("nw")?,
+                ne: dimension("ne")?,
+                sw: dimension("sw")?,
+                se: dimension("se")?,
+            };
+        }
+        Token::Stroke(stroke) => {
+            *stroke = Stroke {
+                width: parse_dimension(value("width")?)?,
+                color: parse_color(value("color")?)?,
+            };
+        }
+        Token::Shadow(shadow) => {
+            *shadow = Shadow {
+                offset: [
+                    parse_dimension(value("offsetX")?)?,
+                    parse_dimension(value("offsetY")?)?,
+                ]
+                .into(),
+                blur: parse_dimension(value("blur")?)?,
+                spread: parse_dimension(value("spread")?)?,
+                color: parse_color(value("color")?)?,
+            };
+        }
+    }
+
+    Ok(())
+}
+
+fn typed(kind: &str, value: Value) -> Value {
+    json!({ "$type": kind, "$value": value })
+}
+
+fn dimension(value: f32) -> Value {
+    typed("dimension", dimension_value(value))
+}
+
+fn dimension_value(value: f32) -> Value {
+    Value::String(format!("{value}px"))
+}
+
+/// Additive colors like `Color32::from_additive_luminance` are lost when
+/// unmultiplied, so those tokens also keep the original in `$extensions`.
+fn color_token(color: Color32) -> Value {
+    let mut token = typed("color", color_value(color));
+    let [r, g, b, a] = color.to_srgba_unmultiplied();
+    if Color32::from_rgba_unmultiplied(r, g, b, a) != color {
+        token["$extensions"] = json!({ "egui": { "premultiplied": color.to_array() } });
+    }
+    token
+}
+
+/// The original color saved by [`color_token`], if any.
+fn premultiplied(token: &Value) -> Option<Color32> {
+    let channels = token
+        .pointer("/$extensions/egui/premultiplied")?
+        .as_array()?;
+    let channel = |i: usize| u8::try_from(channels.get(i)?.as_u64()?).ok();
+
+    Some(Color32::from_rgba_premultiplied(
+        channel(0)?,
+        channel(1)?,
+        channel(2)?,
+        channel(3)?,
+    ))
+}
+
+fn color_value(color: Color32) -> Value {
+    Value::String(tokens::hex_color(color))
+}
+
+/// Find the token or group at a dotted `path`.
+fn lookup<'a>(document: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.')
+        .try_fold(document, |node, segment| node.get(segment))
+}
+
+/// The `$value` of a token, following aliases.
+fn resolve<'a>(document: &'a Value, token: &'a Value) -> Result<&'a Value, String> {
+    let value = token
+        .get("$value")
+        .ok_or("expected a token with a $value")?;
+    resolve_value(document, value)
+}
+
+/// Follow `value` while it's an alias like `"{visuals.window_fill}"`.
+fn resolve_value<'a>(document: &'a Value, mut value: &'a Value) -> Result<&'a Value, String> {
+    for _ in 0..MAX_ALIAS_DEPTH {
+        let Some(alias) = value
+            .as_str()
+            .and_then(|value| value.strip_prefix('{'))
+            .and_then(|value| value.strip_suffix('}'))
+        else {
+            return Ok(value);
+        };
+
+        let token = lookup(document, alias).ok_or_else(|| format!("unknown alias {{{alias}}}"))?;
+        value = token
+            .get("$value")
+            .ok_or_else(|| format!("alias {{{alias}}} isn't a token"))?;
+    }
+
+    Err("too many nested aliases".to_owned())
+}
+
+fn parse_color(value: &Value) -> Result<Color32, String> {
+    let error = || format!("expected a hex color like #rrggbb, found {value}");
+    let hex = value
+        .as_str()
+        .and_then(|value| value.strip_prefix('#'))
+        .ok_or_else(error)?;
+
+    let channel = |i: usize| {
+        hex.get(i * 2..i * 2 + 2)
+            .and_then(|channel| u8::from_str_radix(channel, 16).ok())
+            .ok_or_else(error)
+    };
+
+    match hex.len() {
+        6 => Ok(Color32::from_rgb(channel(0)?, channel(1)?, channel(2)?)),
+        8 => Ok(Color32::from_rgba_unmultiplied(
+            channel(0)?,
+            channel(1)?,
+            channel(2)?,
+            channel(3)?,
+        )),
+        _ => Err(error()),
+    }
+}
+
+/// Accepts `"6px"`, `6` and the newer `{"value": 6, "unit": "px"}`.
+fn parse_dimension(value: &Value) -> Result<f32, String> {
+    let error = || format!("expected a dimension in px, found {value}");
+
+    match value {
+        Value::Number(number) => number.as_f64().map(|n| n as f32).ok_or_else(error),
+        Value::String(string) => string
+            .trim()
+            .strip_suffix("px")
+            .and_then(|number| number.trim().parse().ok())
+            .ok_or_else(error),
+        Value::Object(object) if object.get("unit") == Some(&json!("px")) => object
+            .get("value")
+            .and_then(Value::as_f64)
+            .map(|n| n as f32)
+            .ok_or_else(error),
+        _ => Err(error()),
+    }
+}
@@ -1,27 +1,67 @@
 use std::time::Duration;
 
 use eframe::{
-    egui::{ComboBox, Direction, Layout, Style, Ui},
+    egui::{Button, ComboBox, Context, Direction, DragValue, Layout, Style, Ui},
     emath::Align,
 };
 use egui_notify::Toasts;
-use handlebars::{handlebars_helper, Handlebars, JsonValue};
+use egui_themer::{
+    codegen::{self, EguiVersion, Section, SourceOptions},
+    css, design_tokens,
+    notes::{self, Notes},
+    share,
+};
 use rfd::AsyncFileDialog;
 
 #[cfg(not(target_arch = "wasm32"))]
-use rust_format::{Formatter, RustFmt};
-
-const TEMPLATE: &str = include_str!("template.rs.hbs");
+use crate::{check::CompileCheck, write_back::WriteBack};
 
-#[derive(Default)]
 pub struct ExportMenu {
-    eframe: bool,
+    source: SourceOptions,
     export_format: ExportFormat,
     json_pretty: bool,
+    #[cfg(not(target_arch = "wasm32"))]
+    compile_check: CompileCheck,
+    #[cfg(not(target_arch = "wasm32"))]
+    write_back: WriteBack,
+    crate_name: String,
+    /// The part of the style copied to the clipboard, or all of it.
+    copy_section: Option<Section>,
+    /// The page share links point to.
+    share_url: String,
 }
 
 impl ExportMenu {
-    pub fn ui(&mut self, ui: &mut Ui, style: &Style, toasts: &mut Toasts) {
+    pub fn new(share_url: String) -> Self {
+        Self {
+            source: SourceOptions::default(),
+            export_format: ExportFormat::default(),
+            json_pretty: false,
+            #[cfg(not(target_arch = "wasm32"))]
+            compile_check: CompileCheck::default(),
+            #[cfg(not(target_arch = "wasm32"))]
+            write_back: WriteBack::default(),
+            crate_name: "egui-theme".to_owned(),
+            copy_section: None,
+            share_url,
+        }
+    }
+
+    pub fn ui(&mut self, ui: &mut Ui, style: &Style, notes: &Notes, toasts: &mut Toasts) {
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(source) = self.compile_check.poll(toasts) {
+            self.save(source);
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let (source, json_pretty) = (self.source, self.json_pretty);
+            self.write_back
+                .poll(ui.ctx(), style, toasts, |format, style| {
+                    generate(format, style, notes, &source, json_pretty)
+                });
+        }
+
         ui.add(crate::section_title("Export", None));
 
         ui.horizontal(|ui| {
@@ -31,30 +71,99 @@ impl ExportMenu {
                 ComboBox::from_label("")
                     .selected_text(self.export_format.name())
                     .show_ui(ui, |ui| {
-                        ui.selectable_value(
-                            &mut self.export_format,
-                            ExportFormat::RustSource,
-                            ExportFormat::RustSource.name(),
-                        );
-                        ui.selectable_value(
-                            &mut self.export_format,
-                            ExportFormat::Json,
-                            ExportFormat::Json.name(),
-                        );
+                        for format in ExportFormat::ALL {
+                            ui.selectable_value(&mut self.export_format, format, format.name());
+                        }
                     });
             });
         });
 
         match self.export_format {
-            ExportFormat::RustSource => {
+            ExportFormat::RustSource | ExportFormat::Crate => {
                 ui.horizontal(|ui| {
-                    ui.label("Eframe Support");
+                    ui.label("Target egui Version");
                     ui.with_layout(Layout::right_to_left(Align::Min), |ui| {
-                        ui.checkbox(&mut self.eframe, "")
+                        ComboBox::from_id_source("egui_version")
+                            .selected_text(self.source.version.name())
+                            .show_ui(ui, |ui| {
+                                for version in EguiVersion::ALL {
+                                    ui.selectable_value(
+                                        &mut self.source.version,
+                                        version,
+                                        version.name(),
+                                    );
+                                }
+                            });
                     });
                 });
+                if self.export_format == ExportFormat::Crate {
+                    ui.horizontal(|ui| {
+                        ui.label("Crate Name");
+                        ui.with_layout(Layout::right_to_left(Align::Min), |ui| {
+                            ui.text_edit_singleline(&mut self.crate_name)
+                        });
+                    });
+                    if !codegen::is_crate_name(&self.crate_name) {
+                        ui.colored_label(
+                            ui.visuals().error_fg_color,
+                            "Use letters, digits, '-' and '_', starting with a letter.",
+                        );
+                    }
+                } else {
+                    ui.horizontal(|ui| {
+                        ui.label("Eframe Support");
+                        ui.with_layout(Layout::right_to_left(Align::Min), |ui| {
+                            ui.checkbox(&mut self.source.eframe, "")
+                        });
+                    });
+
+                    #[cfg(not(target_arch = "wasm32"))]
+                    self.compile_check.option_ui(ui, self.source.version);
+                }
+
+                ui.horizontal(|ui| {
+                    ui.label("Lazy Static");
+                    ui.with_layout(Layout::right_to_left(Align::Min), |ui| {
+                        ui.checkbox(&mut self.source.lazy_static, "")
+                            .on_hover_text("Add `pub static STYLE: LazyLock<Style>` (Rust 1.80+).")
+                    });
+                });
+                ui.horizontal(|ui| {
+                    ui.label("Arc Helper");
+                    ui.with_layout(Layout::right_to_left(Align::Min), |ui| {
+                        ui.checkbox(&mut self.source.arc, "")
+                            .on_hover_text("Add `pub fn style_arc() -> Arc<Style>`.")
+                    });
+                });
+                ui.horizontal(|ui| {
+                    ui.label("Apply Function");
+                    ui.with_layout(Layout::right_to_left(Align::Min), |ui| {
+                        let mut apply = self.source.apply.is_some();
+                        ui.checkbox(&mut apply, "").on_hover_text(
+                            "Add `pub fn apply(ctx: &Context)`, setting the style, fonts and scale.",
+                        );
+                        self.source.apply = match (apply, self.source.apply) {
+                            (true, Some(ppp)) => {
+                                let mut ppp = ppp;
+                                ui.add(
+                                    DragValue::new(&mut ppp)
+                                        .speed(0.01)
+                                        .range(0.25..=4.0)
+                                        .prefix("Pixels per Point: "),
+                                );
+                                Some(ppp)
+                            }
+                            (true, None) => Some(ui.ctx().pixels_per_point()),
+                            (false, _) => None,
+                        };
+                    });
+                });
+
+                for warning in self.source.version.warnings(style) {
+                    ui.colored_label(ui.visuals().warn_fg_color, format!("⚠ {warning}"));
+                }
             }
-            ExportFormat::Json => {
+            ExportFormat::Json | ExportFormat::DesignTokens => {
                 ui.horizontal(|ui| {
                     ui.label("Pretty JSON");
                     ui.with_layout(Layout::right_to_left(Align::Min), |ui| {
@@ -62,6 +171,40 @@ impl ExportMenu {
                     });
                 });
             }
+            ExportFormat::Css => {
+                ui.label("Custom properties and a sample stylesheet for matching web UIs.");
+            }
+        }
+
+        ui.allocate_ui_with_layout(
+            [ui.available_width(), 0.0].into(),
+            Layout::centered_and_justified(Direction::TopDown),
+            |ui| {
+                #[cfg(not(target_arch = "wasm32"))]
+                let enabled = !self.compile_check.checking();
+                #[cfg(target_arch = "wasm32")]
+                let enabled = true;
+
+                if ui
+                    .add_enabled(
+                        enabled,
+                        Button::new(format!("Export {}", self.export_format.name())),
+                    )
+                    .clicked()
+                {
+                    self.export(ui.ctx(), style, notes, toasts);
+                }
+            },
+        );
+
+        #[cfg(not(target_arch = "wasm32"))]
+        if self.export_format == ExportFormat::RustSource {
+            self.compile_check.status_ui(ui);
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        if self.write_back.ui(ui, self.export_format) {
+            self.write_back.bind(self.export_format);
         }
 
         ui.allocate_ui_with_layout(
@@ -69,37 +212,164 @@ impl ExportMenu {
             Layout::centered_and_justified(Direction::TopDown),
             |ui| {
                 if ui
-                    .button(format!("Export {}", self.export_format.name()))
+                    .button("🔗 Copy Share Link")
+                    .on_hover_text("A link to the web version with this theme loaded.")
                     .clicked()
                 {
-                    self.export(style, toasts);
+                    self.copy_share_link(ui.ctx(), style, toasts);
                 }
             },
         );
+
+        ui.horizontal(|ui| {
+            ui.label("Copy");
+            ui.with_layout(Layout::right_to_left(Align::Min), |ui| {
+                if ui
+                    .button("JSON")
+                    .on_hover_text("Copy as JSON, pretty if Pretty JSON is on.")
+                    .clicked()
+                {
+                    self.copy(ui.ctx(), style, notes, false, toasts);
+                }
+                if ui
+                    .button("Rust")
+                    .on_hover_text("Copy as Rust, using the Rust Source options.")
+                    .clicked()
+                {
+                    self.copy(ui.ctx(), style, notes, true, toasts);
+                }
+
+                ComboBox::from_id_source("copy_section")
+                    .selected_text(self.copy_section.map_or("Style", Section::type_name))
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(&mut self.copy_section, None, "Style");
+                        for section in Section::ALL {
+                            ui.selectable_value(
+                                &mut self.copy_section,
+                                Some(section),
+                                section.type_name(),
+                            );
+                        }
+                    });
+            });
+        });
     }
 
-    pub fn export(&mut self, style: &Style, toasts: &mut Toasts) {
-        let generated = match (self.export_format, self.json_pretty) {
-            (ExportFormat::RustSource, _) => self.generate_source(style),
-            (ExportFormat::Json, true) => {
-                serde_json::to_string_pretty(&style).map_err(|e| e.to_string())
-            }
-            (ExportFormat::Json, false) => serde_json::to_string(&style).map_err(|e| e.to_string()),
+    /// Copy the style, or just [`Self::copy_section`], as Rust or JSON.
+    fn copy(&self, ctx: &Context, style: &Style, notes: &Notes, rust: bool, toasts: &mut Toasts) {
+        let result = match (rust, self.copy_section) {
+            (true, None) => codegen::generate_source(style, &self.source)
+                .map(|source| notes::annotate_rust(&source, "", notes)),
+            (true, Some(section)) => codegen::generate_snippet(style, section, &self.source)
+                .map(|source| notes::annotate_rust(&source, &section_path(section), notes)),
+            (false, section) => serde_json::to_value(style)
+                .and_then(|mut value| {
+                    let mut value = value
+                        .pointer_mut(section.map_or("", Section::pointer))
+                        .expect("sections are in the style")
+                        .take();
+                    notes::annotate_json(
+                        &mut value,
+                        &section.map_or_else(String::new, section_path),
+                        notes,
+                    );
+                    if self.json_pretty {
+                        serde_json::to_string_pretty(&value)
+                    } else {
+                        serde_json::to_string(&value)
+                    }
+                })
+                .map_err(|e| e.to_string()),
         };
 
-        match generated {
-            Ok(result) => {
-                let dialog = AsyncFileDialog::new()
-                    .set_file_name(format!("style.{}", self.export_format.extension()))
-                    .add_filter(self.export_format.name(), &[self.export_format.extension()])
-                    .save_file();
-
-                crate::execute_future(async move {
-                    if let Some(file) = dialog.await {
-                        _ = file.write(result.as_bytes()).await;
+        match result {
+            Ok(text) => {
+                ctx.output_mut(|o| o.copied_text = text);
+                toasts.info(format!(
+                    "{} copied",
+                    self.copy_section.map_or("Style", Section::type_name)
+                ));
+            }
+            Err(err) => {
+                toasts
+                    .error(format!("Export Error: {err}"))
+                    .set_duration(Some(Duration::from_secs(5)));
+            }
+        }
+    }
+
+    pub fn copy_share_link(&self, ctx: &Context, style: &Style, toasts: &mut Toasts) {
+        match share::link(&self.share_url, style) {
+            Ok(link) => {
+                // keep the address bar in sync, this doesn't reload the page
+                #[cfg(target_arch = "wasm32")]
+                ctx.open_url(eframe::egui::OpenUrl::same_tab(&link));
+
+                ctx.output_mut(|o| o.copied_text = link);
+                toasts.info("Share link copied");
+            }
+            Err(err) => {
+                toasts
+                    .error(format!("Export Error: {err}"))
+                    .set_duration(Some(Duration::from_secs(5)));
+            }
+        }
+    }
+
+    #[cfg_attr(target_arch = "wasm32", allow(unused_variables))]
+    pub fn export(&mut self, ctx: &Context, style: &Style, notes: &Notes, toasts: &mut Toasts) {
+        let warnings = self.source.version.warnings(style).len();
+        if matches!(
+            self.export_format,
+            ExportFormat::RustSource | ExportFormat::Crate
+        ) && warnings > 0
+        {
+            toasts
+                .warning(format!(
+                    "{warnings} field(s) can't be represented in egui {}",
+                    self.source.version.name()
+                ))
+                .set_duration(Some(Duration::from_secs(5)));
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        if self.export_format == ExportFormat::Crate {
+            match codegen::generate_crate(style, &self.crate_name, &self.source) {
+                Ok(mut files) => {
+                    for (path, contents) in &mut files {
+                        if *path == "src/style.rs" {
+                            *contents = notes::annotate_rust(contents, "", notes);
+                        }
                     }
-                });
+                    self.save_crate(files, toasts);
+                }
+                Err(err) => {
+                    toasts
+                        .error(format!("Export Error: {err}"))
+                        .set_duration(Some(Duration::from_secs(5)));
+                }
+            }
+            return;
+        }
+
+        let generated = generate(
+            self.export_format,
+            style,
+            notes,
+            &self.source,
+            self.json_pretty,
+        );
+
+        match generated {
+            #[cfg(not(target_arch = "wasm32"))]
+            Ok(result)
+                if self.export_format == ExportFormat::RustSource
+                    && self.compile_check.enabled() =>
+            {
+                self.compile_check
+                    .start(ctx, result, self.source.eframe, self.source.version);
             }
+            Ok(result) => self.save(result),
             Err(err) => {
                 toasts
                     .error(format!("Export Error: {err}"))
@@ -108,103 +378,140 @@ impl ExportMenu {
         }
     }
 
-    fn generate_source(&self, style: &Style) -> Result<String, String> {
-        let mut reg = Handlebars::new();
+    /// Write the crate's files into a new directory in the picked folder.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn save_crate(&self, files: Vec<(&'static str, String)>, toasts: &mut Toasts) {
+        let Some(folder) = rfd::FileDialog::new().pick_folder() else {
+            return;
+        };
 
-        reg.register_template_string("template", TEMPLATE)
-            .map_err(|err| err.to_string())?;
+        let root = folder.join(&self.crate_name);
+        if root.exists() {
+            toasts
+                .error(format!("Export Error: {} already exists", root.display()))
+                .set_duration(Some(Duration::from_secs(5)));
+            return;
+        }
 
-        reg.register_helper("vec2", Box::new(vec2));
-        reg.register_helper("stroke", Box::new(stroke));
-        reg.register_helper("rounding", Box::new(rounding));
-        reg.register_helper("color32", Box::new(color32));
-        reg.register_helper("widgetvisuals", Box::new(widgetvisuals));
+        let written = files.into_iter().try_for_each(|(path, contents)| {
+            let path = root.join(path);
+            std::fs::create_dir_all(path.parent().unwrap_or(&root))?;
+            std::fs::write(path, contents)
+        });
 
-        let res = reg
-            .render(
-                "template",
-                &serde_json::json!({
-                    "eframe": self.eframe,
-                    "style": style,
-                }),
-            )
-            .map_err(|err| err.to_string())?;
+        match written {
+            Ok(()) => {
+                toasts.info(format!("Exported crate to {}", root.display()));
+            }
+            Err(err) => {
+                toasts
+                    .error(format!("Export Error: {err}"))
+                    .set_duration(Some(Duration::from_secs(5)));
+            }
+        }
+    }
 
-        #[cfg(not(target_arch = "wasm32"))]
-        let res = RustFmt::default()
-            .format_str(res)
-            .map_err(|err| err.to_string())?;
+    fn save(&self, result: String) {
+        let dialog = AsyncFileDialog::new()
+            .set_file_name(format!("style.{}", self.export_format.extension()))
+            .add_filter(self.export_format.name(), &[self.export_format.extension()])
+            .save_file();
+
+        crate::execute_future(async move {
+            if let Some(file) = dialog.await {
+                _ = file.write(result.as_bytes()).await;
+            }
+        });
+    }
+}
 
-        Ok(res)
+/// Render `style` in any format but [`ExportFormat::Crate`], which is
+/// several files.
+fn generate(
+    format: ExportFormat,
+    style: &Style,
+    notes: &Notes,
+    source: &SourceOptions,
+    json_pretty: bool,
+) -> Result<String, String> {
+    match (format, json_pretty) {
+        (ExportFormat::RustSource, _) => codegen::generate_source(style, source)
+            .map(|source| notes::annotate_rust(&source, "", notes)),
+        (ExportFormat::Crate, _) => {
+            Err("Crates can only be exported from the desktop app".to_owned())
+        }
+        // going through a `Value` sorts the fields, so only do it for notes
+        (ExportFormat::Json, _) if !notes.is_empty() => serde_json::to_value(style)
+            .and_then(|mut value| {
+                notes::annotate_json(&mut value, "", notes);
+                if json_pretty {
+                    serde_json::to_string_pretty(&value)
+                } else {
+                    serde_json::to_string(&value)
+                }
+            })
+            .map_err(|e| e.to_string()),
+        (ExportFormat::Json, true) => {
+            serde_json::to_string_pretty(&style).map_err(|e| e.to_string())
+        }
+        (ExportFormat::Json, false) => serde_json::to_string(&style).map_err(|e| e.to_string()),
+        (ExportFormat::Css, _) => Ok(css::generate_css(style)),
+        (ExportFormat::DesignTokens, true) => {
+            serde_json::to_string_pretty(&design_tokens::generate_tokens(style))
+                .map_err(|e| e.to_string())
+        }
+        (ExportFormat::DesignTokens, false) => {
+            serde_json::to_string(&design_tokens::generate_tokens(style)).map_err(|e| e.to_string())
+        }
     }
 }
 
+/// The dotted path of `section`'s field in the style, e.g. `visuals.widgets`.
+fn section_path(section: Section) -> String {
+    section.pointer()[1..].replace('/', ".")
+}
+
 #[derive(Copy, Clone, Default, PartialEq, Eq)]
-enum ExportFormat {
+pub enum ExportFormat {
     #[default]
     RustSource,
+    /// A library crate wrapping the Rust source, written as a directory.
+    /// Only offered on native, where there's a filesystem to write it to.
+    Crate,
     Json,
+    Css,
+    DesignTokens,
 }
 
 impl ExportFormat {
+    #[cfg(not(target_arch = "wasm32"))]
+    const ALL: [Self; 5] = [
+        Self::RustSource,
+        Self::Crate,
+        Self::Json,
+        Self::Css,
+        Self::DesignTokens,
+    ];
+    #[cfg(target_arch = "wasm32")]
+    const ALL: [Self; 4] = [Self::RustSource, Self::Json, Self::Css, Self::DesignTokens];
+
     pub fn name(self) -> &'static str {
         match self {
             ExportFormat::RustSource => "Rust Source",
+            ExportFormat::Crate => "Cargo Crate",
             ExportFormat::Json => "JSON",
+            ExportFormat::Css => "CSS",
+            ExportFormat::DesignTokens => "Design Tokens",
         }
     }
 
     pub fn extension(self) -> &'static str {
         match self {
             ExportFormat::RustSource => "rs",
+            ExportFormat::Crate => "toml",
             ExportFormat::Json => "json",
+            ExportFormat::Css => "css",
+            ExportFormat::DesignTokens => "json",
         }
     }
 }
-
-handlebars_helper!(vec2: |value: JsonValue| format!("Vec2 {{ x: {}, y: {}}}", &value["x"], &value["y"]));
-handlebars_helper!(stroke: |value: JsonValue| gen_stroke(&value));
-handlebars_helper!(rounding: |value: JsonValue| gen_rounding(&value));
-handlebars_helper!(color32: |color: JsonValue| gen_color32(&color));
-handlebars_helper!(widgetvisuals: |value: JsonValue| {
-    format!("WidgetVisuals {{
-        bg_fill: {},
-        weak_bg_fill: {},
-        bg_stroke: {},
-        rounding: {},
-        fg_stroke: {},
-        expansion: {},
-    }}",
-        gen_color32(&value["bg_fill"]),
-        gen_color32(&value["weak_bg_fill"]),
-        gen_stroke(&value["bg_stroke"]),
-        gen_rounding(&value["rounding"]),
-        gen_stroke(&value["fg_stroke"]),
-        value["expansion"]
-    )
-});
-
-fn gen_color32(value: &JsonValue) -> String {
-    format!(
-        "Color32::from_rgba_premultiplied({}, {}, {}, {})",
-        value[0], value[1], value[2], value[3]
-    )
-}
-
-fn gen_stroke(value: &JsonValue) -> String {
-    format!(
-        "Stroke {{
-            width: {},
-            color: {},
-        }}",
-        value["width"],
-        gen_color32(&value["color"]),
-    )
-}
-
-fn gen_rounding(value: &JsonValue) -> String {
-    format!(
-        "Rounding {{ nw: {}, ne: {}, sw: {}, se: {} }}",
-        value["nw"], value["ne"], value["sw"], value["se"]
-    )
-}
@@ -0,0 +1,348 @@
+use std::collections::BTreeMap;
+
+use eframe::{
+    egui::{
+        Button, Context, Id, Key, Layout, Modifiers, Rect, Response, RichText, Sense, Style,
+        TextEdit, Ui, Widget,
+    },
+    emath::Align,
+};
+use egui_themer::notes::Notes;
+use serde_json::Value;
+
+/// State shared by every picker in the side panel, keyed by the `Style`
+/// field path each picker edits (e.g. `visuals.widgets.hovered.bg_stroke`).
+#[derive(Default)]
+pub struct Fields {
+    filter: String,
+    breadcrumb: String,
+    matches: usize,
+    inspected: Option<String>,
+    scroll: bool,
+    focus_search: bool,
+    /// The focus target and area of every picker shown, in order, for
+    /// PageUp/PageDown.
+    pickers: Vec<(Id, Rect)>,
+    /// A named style standing in for egui's defaults.
+    baseline: Option<(String, Style)>,
+    /// Locked field paths, with their values as of the end of the last frame.
+    locked: BTreeMap<String, Option<Value>>,
+    /// Whether a reset, paste or derive changed many fields this frame.
+    bulk_edited: bool,
+    notes: Notes,
+    /// The path whose note is being edited, and whether its text box still
+    /// needs focusing.
+    editing_note: Option<(String, bool)>,
+}
+
+impl Fields {
+    /// Show the search box. Must be called before any pickers are added each frame.
+    pub fn filter_ui(&mut self, ui: &mut Ui) {
+        self.matches = 0;
+        self.focus_adjacent_picker(ui);
+        self.pickers.clear();
+
+        ui.horizontal(|ui| {
+            ui.label("🔍");
+            ui.with_layout(Layout::right_to_left(Align::Min), |ui| {
+                if ui.add_enabled(self.filtering(), Button::new("🗙")).clicked() {
+                    self.filter.clear();
+                }
+                let edit = ui.add(
+                    TextEdit::singleline(&mut self.filter)
+                        .hint_text("Search fields, e.g. \"rounding\" or \"scroll\"")
+                        .desired_width(f32::INFINITY),
+                );
+                if std::mem::take(&mut self.focus_search) {
+                    edit.request_focus();
+                }
+            });
+        });
+    }
+
+    pub fn focus_search(&mut self) {
+        self.focus_search = true;
+    }
+
+    /// PageDown and PageUp move the keyboard focus to the next or previous
+    /// picker shown last frame, where Tab goes on to its values.
+    fn focus_adjacent_picker(&self, ui: &mut Ui) {
+        // text boxes outside the pickers keep their keys
+        let current = self.focused_picker(ui.ctx());
+        if self.pickers.is_empty() || (current.is_none() && ui.ctx().wants_keyboard_input()) {
+            return;
+        }
+
+        let (next, previous) = ui.input_mut(|i| {
+            (
+                i.consume_key(Modifiers::NONE, Key::PageDown),
+                i.consume_key(Modifiers::NONE, Key::PageUp),
+            )
+        });
+        if !next && !previous {
+            return;
+        }
+
+        let last = self.pickers.len() - 1;
+        let target = match (current, next) {
+            (Some(i), true) => (i + 1).min(last),
+            (Some(i), false) => i.saturating_sub(1),
+            (None, true) => 0,
+            (None, false) => last,
+        };
+
+        ui.memory_mut(|m| m.request_focus(self.pickers[target].0));
+    }
+
+    /// The index of the picker shown last frame that has the keyboard focus,
+    /// either itself or through one of its values.
+    fn focused_picker(&self, ctx: &Context) -> Option<usize> {
+        let focused = ctx.memory(|m| m.focused())?;
+        let rect = ctx.read_response(focused).map(|resp| resp.rect);
+        self.pickers.iter().position(|(id, picker)| {
+            *id == focused || rect.is_some_and(|rect| picker.contains_rect(rect))
+        })
+    }
+
+    /// Whether the keyboard focus is on a picker or one of its values, rather
+    /// than a text box elsewhere.
+    pub fn picker_focused(&self, ctx: &Context) -> bool {
+        self.focused_picker(ctx).is_some()
+    }
+
+    /// The style ⟲ buttons revert to instead of egui's defaults, if one is set.
+    pub fn baseline(&self) -> Option<&Style> {
+        self.baseline.as_ref().map(|(_, style)| style)
+    }
+
+    pub fn baseline_name(&self) -> Option<&str> {
+        self.baseline.as_ref().map(|(name, _)| name.as_str())
+    }
+
+    pub fn set_baseline(&mut self, baseline: Option<(String, Style)>) {
+        self.baseline = baseline;
+    }
+
+    /// Note that many fields were just changed at once, so locked ones are put
+    /// back at the end of the frame.
+    pub fn bulk_edit(&mut self) {
+        self.bulk_edited = true;
+    }
+
+    /// Put locked fields that were changed since the last frame back, by
+    /// imports, resets or anything else. Returns true if any were.
+    pub fn restore_locked(&self, style: &mut Style) -> bool {
+        if self.locked.values().all(Option::is_none) {
+            return false;
+        }
+        let Ok(mut value) = serde_json::to_value(&*style) else {
+            return false;
+        };
+
+        let mut restored = false;
+        for (path, pinned) in &self.locked {
+            if let (Some(pinned), Some(field)) = (pinned, value.pointer_mut(&pointer(path))) {
+                if field != pinned {
+                    *field = pinned.clone();
+                    restored = true;
+                }
+            }
+        }
+
+        match serde_json::from_value(value) {
+            Ok(value) if restored => {
+                *style = value;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Call once the pickers have edited `style`: restores locked fields if
+    /// there was a bulk edit, then remembers their values for next frame.
+    /// Returns true if any were restored.
+    pub fn finish_locked(&mut self, style: &mut Style) -> bool {
+        let restored = std::mem::take(&mut self.bulk_edited) && self.restore_locked(style);
+
+        if !self.locked.is_empty() {
+            if let Ok(value) = serde_json::to_value(&*style) {
+                for (path, pinned) in &mut self.locked {
+                    *pinned = value.pointer(&pointer(path)).cloned();
+                }
+            }
+        }
+
+        restored
+    }
+
+    /// Notes on fields, exported as comments beside them.
+    pub fn notes(&self) -> &Notes {
+        &self.notes
+    }
+
+    pub fn set_notes(&mut self, notes: Notes) {
+        self.notes = notes;
+        self.editing_note = None;
+    }
+
+    pub fn filtering(&self) -> bool {
+        !self.filter.trim().is_empty()
+    }
+
+    /// Number of pickers that matched the search so far this frame.
+    pub fn matches(&self) -> usize {
+        self.matches
+    }
+
+    /// Set the breadcrumb shown above search results for the following pickers.
+    pub fn section(&mut self, breadcrumb: impl Into<String>) {
+        self.breadcrumb = breadcrumb.into();
+    }
+
+    pub fn inspect(&mut self, path: &str) {
+        self.inspected = Some(path.to_owned());
+        self.scroll = true;
+        self.filter.clear();
+    }
+
+    /// Add the picker for the field at `path`, hiding it if it doesn't match
+    /// the search and outlining it if it's being inspected.
+    pub fn add<'a, W: Widget>(
+        &mut self,
+        ui: &mut Ui,
+        title: &'a str,
+        path: &str,
+        picker: impl FnOnce(&'a str) -> W,
+    ) -> Option<Response> {
+        if self.filtering() {
+            let filter = self.filter.trim().to_lowercase();
+            let noted = self
+                .notes
+                .get(path)
+                .is_some_and(|note| note.to_lowercase().contains(&filter));
+            if !title.to_lowercase().contains(&filter)
+                && !path.to_lowercase().contains(&filter)
+                && !noted
+            {
+                return None;
+            }
+
+            self.matches += 1;
+            ui.label(
+                RichText::new(format!("{} › {path}", self.breadcrumb))
+                    .small()
+                    .weak(),
+            );
+        }
+
+        let locked = self.locked.contains_key(path);
+        let editing = match &mut self.editing_note {
+            Some((editing, focus)) if editing == path => Some(std::mem::take(focus)),
+            _ => None,
+        };
+        let notes = &mut self.notes;
+        let (resp, lock, note, note_done) = ui
+            .push_id(path, |ui| {
+                ui.horizontal(|ui| {
+                    let icon = if locked {
+                        RichText::new("🔒").color(ui.visuals().warn_fg_color)
+                    } else {
+                        RichText::new("🔓").weak()
+                    };
+                    let lock = ui.add(Button::new(icon).frame(false)).on_hover_text(
+                        "Lock this field so resets, imports and derived states leave it alone.",
+                    );
+
+                    let icon = match notes.contains_key(path) {
+                        true => RichText::new("✏"),
+                        false => RichText::new("✏").weak(),
+                    };
+                    let note = ui
+                        .add(Button::new(icon).frame(false))
+                        .on_hover_text("Note why this value was picked, exported as a comment.");
+
+                    ui.vertical(|ui| {
+                        let resp = ui.add(picker(title));
+
+                        let mut note_done = false;
+                        if let Some(focus) = editing {
+                            let edit = ui.add(
+                                TextEdit::multiline(notes.entry(path.to_owned()).or_default())
+                                    .hint_text("e.g. matches the brand guideline's primary")
+                                    .desired_rows(1)
+                                    .desired_width(f32::INFINITY),
+                            );
+                            if focus {
+                                edit.request_focus();
+                            }
+                            note_done = edit.lost_focus();
+                        } else if let Some(text) = notes.get(path) {
+                            ui.label(RichText::new(text).small().weak());
+                        }
+
+                        (resp, lock, note, note_done)
+                    })
+                    .inner
+                })
+                .inner
+            })
+            .inner;
+
+        if note.clicked() || note_done {
+            self.editing_note = match editing {
+                Some(_) => None,
+                None => Some((path.to_owned(), true)),
+            };
+            self.notes.retain(|_, note| !note.trim().is_empty());
+        }
+
+        if lock.clicked() {
+            if locked {
+                self.locked.remove(path);
+            } else {
+                self.locked.insert(path.to_owned(), None);
+            }
+        }
+        if locked {
+            ui.painter().rect_stroke(
+                resp.rect.expand(1.0),
+                ui.visuals().menu_rounding,
+                (1.0, ui.visuals().warn_fg_color),
+            );
+        }
+
+        let id = Id::new("picker").with(path);
+        let focus = ui.interact(resp.rect, id, Sense::focusable_noninteractive());
+        self.pickers.push((id, resp.rect));
+        if focus.has_focus() {
+            ui.painter().rect_stroke(
+                resp.rect.expand(1.0),
+                ui.visuals().menu_rounding,
+                ui.visuals().widgets.active.fg_stroke,
+            );
+            if focus.gained_focus() {
+                resp.scroll_to_me(None);
+            }
+        }
+
+        if self.inspected.as_deref() == Some(path) {
+            ui.painter().rect_stroke(
+                resp.rect.expand(1.0),
+                ui.visuals().menu_rounding,
+                ui.visuals().selection.stroke,
+            );
+
+            if self.scroll {
+                resp.scroll_to_me(Some(Align::Center));
+                self.scroll = false;
+            }
+        }
+
+        Some(resp)
+    }
+}
+
+/// The JSON pointer to a field path like `visuals.widgets.hovered.bg_stroke`.
+fn pointer(path: &str) -> String {
+    format!("/{}", path.replace('.', "/"))
+}
@@ -0,0 +1,303 @@
+use eframe::egui::{
+    scroll_area::ScrollBarVisibility, CentralPanel, CollapsingHeader, ComboBox, Context, DragValue,
+    Frame, Grid, Response, RichText, ScrollArea, Sense, Slider, TextEdit, Ui,
+};
+
+/// A single page showing every styled element at once, with the widget
+/// states forced so each `WidgetVisuals` can be inspected without the mouse.
+pub struct Gallery {
+    inspecting: bool,
+    text: String,
+    checked: bool,
+    radio: bool,
+    value: f32,
+    combo: usize,
+}
+
+impl Default for Gallery {
+    fn default() -> Self {
+        Self {
+            inspecting: false,
+            text: "Editable text".to_owned(),
+            checked: true,
+            radio: true,
+            value: 42.0,
+            combo: 0,
+        }
+    }
+}
+
+impl Gallery {
+    /// Show the gallery, returning the style field path of the element
+    /// clicked while inspecting.
+    pub fn ui(&mut self, ctx: &Context) -> Option<String> {
+        CentralPanel::default()
+            .show(ctx, |ui| {
+                ui.checkbox(
+                    &mut self.inspecting,
+                    "🔍 Click an element to inspect its style",
+                )
+                .on_hover_text("Jump to the picker that styles the clicked element.");
+                ui.separator();
+
+                ScrollArea::vertical()
+                    .show(ui, |ui| {
+                        let mut targets = Vec::new();
+
+                        ui.heading("Widget States");
+                        self.widget_states(ui, &mut targets);
+                        ui.separator();
+
+                        ui.heading("Text");
+                        self.text(ui, &mut targets);
+                        ui.separator();
+
+                        ui.heading("Containers");
+                        self.containers(ui, &mut targets);
+
+                        if self.inspecting {
+                            inspect_targets(ui, targets)
+                        } else {
+                            None
+                        }
+                    })
+                    .inner
+            })
+            .inner
+    }
+
+    fn widget_states(&mut self, ui: &mut Ui, targets: &mut Vec<(Response, String)>) {
+        Grid::new("gallery_widget_states")
+            .num_columns(7)
+            .spacing([12.0, 8.0])
+            .show(ui, |ui| {
+                ui.label("");
+                for header in [
+                    "Button",
+                    "Checkbox",
+                    "Radio",
+                    "Slider",
+                    "Drag Value",
+                    "Combo Box",
+                ] {
+                    ui.strong(header);
+                }
+                ui.end_row();
+
+                for state in ForcedState::ALL {
+                    ui.label(state.name());
+                    for column in 0..6 {
+                        let resp = state.scope(ui, |ui| match column {
+                            0 => ui.button("Button"),
+                            1 => ui.checkbox(&mut self.checked, ""),
+                            2 => ui.radio_value(&mut self.radio, true, ""),
+                            3 => {
+                                ui.add(Slider::new(&mut self.value, 0.0..=100.0).show_value(false))
+                            }
+                            4 => ui.add(DragValue::new(&mut self.value)),
+                            _ => ComboBox::from_id_source(("gallery_combo", state.name()))
+                                .selected_text(COMBO_OPTIONS[self.combo])
+                                .show_index(ui, &mut self.combo, COMBO_OPTIONS.len(), |i| {
+                                    COMBO_OPTIONS[i]
+                                }),
+                        });
+
+                        // buttons and combo boxes are filled with the weak background
+                        let fill = match column {
+                            0 | 5 => "weak_bg_fill",
+                            _ => "bg_fill",
+                        };
+                        targets.push((resp, format!("visuals.widgets.{}.{fill}", state.field())));
+                    }
+                    ui.end_row();
+                }
+            });
+
+        ui.add_space(4.0);
+        ui.horizontal(|ui| {
+            ui.label("Noninteractive:");
+            let resp = ui.add_enabled(false, eframe::egui::Button::new("Disabled Button"));
+            targets.push((
+                resp,
+                "visuals.widgets.noninteractive.weak_bg_fill".to_owned(),
+            ));
+            ui.separator();
+            let resp = ui.label("Plain label");
+            targets.push((resp, "visuals.widgets.noninteractive.fg_stroke".to_owned()));
+        });
+    }
+
+    fn text(&mut self, ui: &mut Ui, targets: &mut Vec<(Response, String)>) {
+        ui.horizontal(|ui| {
+            let resp = ui.selectable_label(true, "Selected");
+            targets.push((resp, "visuals.selection".to_owned()));
+            let _ = ui.selectable_label(false, "Unselected");
+            let resp = ui.hyperlink_to("Hyperlink", "https://github.com/grantshandy/egui-themer/");
+            targets.push((resp, "visuals.hyperlink_color".to_owned()));
+        });
+        ui.horizontal(|ui| {
+            let resp = ui.code("let code = \"background\";");
+            targets.push((resp, "visuals.code_bg_color".to_owned()));
+            let resp = ui.colored_label(ui.visuals().warn_fg_color, "⚠ Warning");
+            targets.push((resp, "visuals.warn_fg_color".to_owned()));
+            let resp = ui.colored_label(ui.visuals().error_fg_color, "🗙 Error");
+            targets.push((resp, "visuals.error_fg_color".to_owned()));
+        });
+        let resp = ui.add(TextEdit::singleline(&mut self.text));
+        targets.push((resp, "visuals.extreme_bg_color".to_owned()));
+        ui.label(RichText::new("Weak text").weak());
+        ui.label(RichText::new("Strong text").strong());
+    }
+
+    fn containers(&mut self, ui: &mut Ui, targets: &mut Vec<(Response, String)>) {
+        ui.horizontal_top(|ui| {
+            ui.vertical(|ui| {
+                ui.label("Striped Grid");
+                let resp = Grid::new("gallery_striped_grid")
+                    .striped(true)
+                    .num_columns(2)
+                    .show(ui, |ui| {
+                        for row in 0..5 {
+                            ui.label(format!("Row {row}"));
+                            ui.label("Value");
+                            ui.end_row();
+                        }
+                    })
+                    .response;
+                targets.push((resp, "visuals.faint_bg_color".to_owned()));
+            });
+
+            ui.vertical(|ui| {
+                ui.label("Scroll Bars");
+                let resp = Frame::canvas(ui.style()).show(ui, |ui| {
+                    ScrollArea::both()
+                        .id_source("gallery_scroll")
+                        .max_height(90.0)
+                        .max_width(160.0)
+                        .scroll_bar_visibility(ScrollBarVisibility::AlwaysVisible)
+                        .show(ui, |ui| {
+                            for line in 0..12 {
+                                ui.label(format!("Scrollable line number {line} with some width"));
+                            }
+                        });
+                });
+                targets.push((resp.response, "visuals.widgets.inactive.bg_fill".to_owned()));
+            });
+        });
+
+        ui.add_space(4.0);
+        let resp = CollapsingHeader::new("Collapsing Header (open)")
+            .default_open(true)
+            .show(ui, |ui| {
+                ui.label("Indented contents");
+                CollapsingHeader::new("Collapsing Header (closed)").show(ui, |ui| {
+                    ui.label("Hidden contents");
+                });
+            })
+            .header_response;
+        targets.push((resp, "visuals.collapsing_header_frame".to_owned()));
+
+        ui.add_space(4.0);
+        ui.horizontal_top(|ui| {
+            ui.vertical(|ui| {
+                ui.label("Tooltip");
+                let resp = Frame::popup(ui.style()).show(ui, |ui| {
+                    ui.label("Tooltip text");
+                });
+                targets.push((resp.response, "visuals.popup_shadow".to_owned()));
+            });
+
+            ui.vertical(|ui| {
+                ui.label("Popup Menu");
+                let resp = Frame::menu(ui.style()).show(ui, |ui| {
+                    ui.set_max_width(120.0);
+                    for option in COMBO_OPTIONS {
+                        let _ = ui.selectable_label(option == COMBO_OPTIONS[self.combo], option);
+                    }
+                });
+                targets.push((resp.response, "visuals.menu_rounding".to_owned()));
+            });
+
+            ui.vertical(|ui| {
+                ui.label("Group");
+                let resp = ui.group(|ui| {
+                    ui.label("Grouped contents");
+                });
+                targets.push((
+                    resp.response,
+                    "visuals.widgets.noninteractive.bg_stroke".to_owned(),
+                ));
+            });
+        });
+    }
+}
+
+const COMBO_OPTIONS: [&str; 3] = ["First", "Second", "Third"];
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum ForcedState {
+    Inactive,
+    Hovered,
+    Active,
+    Open,
+}
+
+impl ForcedState {
+    const ALL: [Self; 4] = [Self::Inactive, Self::Hovered, Self::Active, Self::Open];
+
+    fn name(self) -> &'static str {
+        match self {
+            ForcedState::Inactive => "Inactive",
+            ForcedState::Hovered => "Hovered",
+            ForcedState::Active => "Active",
+            ForcedState::Open => "Open",
+        }
+    }
+
+    fn field(self) -> &'static str {
+        match self {
+            ForcedState::Inactive => "inactive",
+            ForcedState::Hovered => "hovered",
+            ForcedState::Active => "active",
+            ForcedState::Open => "open",
+        }
+    }
+
+    /// Widgets draw with `widgets.inactive` when left alone, so swapping it
+    /// for another state's visuals makes them look permanently in that state.
+    fn scope(self, ui: &mut Ui, add_contents: impl FnOnce(&mut Ui) -> Response) -> Response {
+        ui.scope(|ui| {
+            let widgets = &mut ui.visuals_mut().widgets;
+            widgets.inactive = match self {
+                ForcedState::Inactive => widgets.inactive,
+                ForcedState::Hovered => widgets.hovered,
+                ForcedState::Active => widgets.active,
+                ForcedState::Open => widgets.open,
+            };
+            add_contents(ui)
+        })
+        .inner
+    }
+}
+
+/// Cover each target with a clickable overlay that outlines it on hover.
+fn inspect_targets(ui: &mut Ui, targets: Vec<(Response, String)>) -> Option<String> {
+    let mut picked = None;
+
+    for (resp, path) in targets {
+        let overlay = ui
+            .interact(resp.rect, resp.id.with("inspect"), Sense::click())
+            .on_hover_text(&path);
+
+        if overlay.hovered() {
+            ui.painter()
+                .rect_stroke(overlay.rect.expand(2.0), 2.0, ui.visuals().selection.stroke);
+        }
+
+        if overlay.clicked() {
+            picked = Some(path);
+        }
+    }
+
+    picked
+}
@@ -1,47 +1,338 @@
-use std::sync::mpsc::Sender;
+use std::sync::{mpsc::Sender, Arc, Mutex};
 
-use eframe::egui::{Context, Direction, Layout, Style, Ui};
+use eframe::{
+    egui::{
+        Button, CollapsingHeader, Context, Direction, DroppedFile, Event, Grid, Key, Layout, Style,
+        TextEdit, Ui,
+    },
+    emath::Align,
+};
 use egui_notify::Toast;
+use egui_themer::{
+    clipboard, design_tokens,
+    migrate::{self, Migrated, SkippedField},
+    notes::{self, Notes},
+    share,
+};
 
+#[cfg(not(target_arch = "wasm32"))]
+use crate::watch::{FileWatch, Watched};
+
+/// An imported style, with its notes if it was a project.
+type Imported = (Style, Option<Notes>);
+
+#[derive(Default)]
+pub struct ImportMenu {
+    lenient: bool,
+    /// A share link (or bare share string) pasted by the user.
+    link: String,
+    report: Arc<Mutex<Report>>,
+    /// A baseline file that's been loaded but not handed to `Fields` yet.
+    baseline: Arc<Mutex<Option<(String, Style)>>>,
+    /// An import that's been loaded but not applied yet.
+    imported: Arc<Mutex<Option<Imported>>>,
+    #[cfg(not(target_arch = "wasm32"))]
+    watch: FileWatch,
+}
+
+/// What happened to the fields of the last imported file.
 #[derive(Default)]
-pub struct ImportMenu;
+struct Report {
+    migrations: Vec<String>,
+    skipped: Vec<SkippedField>,
+}
 
 impl ImportMenu {
     pub fn ui(&mut self, ui: &mut Ui, ctx: &Context, toasts_tx: Sender<Toast>) {
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some((path, bytes)) = self.watch.poll(ctx) {
+            let name = path.to_string_lossy();
+            let result = bytes.and_then(|bytes| load(&name, &bytes, self.lenient, &ctx.style()));
+            // a broken save keeps the last good style until it's fixed
+            let toast = match result {
+                Ok(migrated) => {
+                    finish_import(&self.imported, &self.report, Ok(migrated), "Reloaded")
+                }
+                Err(e) => Toast::error(format!("Reload Failed: {e}")),
+            };
+            toasts_tx.send(toast).unwrap();
+        }
+
         ui.add(crate::section_title("Import", None));
 
+        ui.horizontal(|ui| {
+            ui.label("Lenient");
+            ui.with_layout(Layout::right_to_left(Align::Min), |ui| {
+                ui.checkbox(&mut self.lenient, "").on_hover_text(
+                    "Load every field that can be read, keeping the current value for the rest. \
+                     Design token files are always imported this way.",
+                )
+            });
+        });
+
+        #[cfg(not(target_arch = "wasm32"))]
+        self.watch.ui(ui);
+
         ui.allocate_ui_with_layout(
             [ui.available_width(), 0.0].into(),
             Layout::centered_and_justified(Direction::TopDown),
             |ui| {
                 if ui.button("Import JSON").clicked() {
-                    self.import(toasts_tx, ctx.clone());
+                    self.import(toasts_tx.clone(), ctx.clone());
+                }
+            },
+        );
+
+        // Ctrl+V anywhere outside a text box pastes a theme
+        let pasted = ctx.input(|i| {
+            i.events.iter().find_map(|event| match event {
+                Event::Paste(text) => Some(text.clone()),
+                _ => None,
+            })
+        });
+        if let Some(text) = pasted.filter(|_| !ctx.wants_keyboard_input()) {
+            toasts_tx.send(self.paste(ctx, &text)).unwrap();
+        }
+
+        // the browser only hands over the clipboard in a paste event
+        #[cfg(not(target_arch = "wasm32"))]
+        ui.allocate_ui_with_layout(
+            [ui.available_width(), 0.0].into(),
+            Layout::centered_and_justified(Direction::TopDown),
+            |ui| {
+                if ui
+                    .button("📋 Paste Theme")
+                    .on_hover_text(
+                        "Load a share link, design tokens, or the JSON or Rust of a style \
+                         or one of its sections. Ctrl+V works too.",
+                    )
+                    .clicked()
+                {
+                    let toast = match arboard::Clipboard::new().and_then(|mut c| c.get_text()) {
+                        Ok(text) => self.paste(ctx, &text),
+                        Err(e) => Toast::error(format!("Paste Failed: {e}")),
+                    };
+                    toasts_tx.send(toast).unwrap();
                 }
             },
         );
+        #[cfg(target_arch = "wasm32")]
+        ui.weak("Press Ctrl+V to paste a theme as a link, JSON or Rust.");
+
+        ui.horizontal(|ui| {
+            ui.with_layout(Layout::right_to_left(Align::Min), |ui| {
+                let load = ui.add_enabled(!self.link.trim().is_empty(), Button::new("Load"));
+                let edit = ui.add_sized(
+                    [ui.available_width(), 0.0],
+                    TextEdit::singleline(&mut self.link).hint_text("Paste a share link"),
+                );
+
+                let submitted = edit.lost_focus() && ui.input(|i| i.key_pressed(Key::Enter));
+                if load.clicked() || (submitted && !self.link.trim().is_empty()) {
+                    let toast = finish_import(
+                        &self.imported,
+                        &self.report,
+                        share::decode(&self.link).map(|migrated| (migrated, None)),
+                        "Import Successful",
+                    );
+                    toasts_tx.send(toast).unwrap();
+                    self.link.clear();
+                }
+            });
+        });
+
+        let report = self.report.lock().unwrap();
+
+        if !report.skipped.is_empty() {
+            CollapsingHeader::new(format!("{} Field(s) Skipped", report.skipped.len()))
+                .id_source("import_skipped")
+                .default_open(true)
+                .show(ui, |ui| {
+                    Grid::new("import_skipped_grid")
+                        .num_columns(2)
+                        .striped(true)
+                        .show(ui, |ui| {
+                            ui.strong("Field");
+                            ui.strong("Reason");
+                            ui.end_row();
+
+                            for field in &report.skipped {
+                                ui.monospace(&field.path);
+                                ui.small(&field.reason);
+                                ui.end_row();
+                            }
+                        });
+                });
+        }
+
+        if !report.migrations.is_empty() {
+            CollapsingHeader::new(format!("{} Migration(s) Applied", report.migrations.len()))
+                .id_source("import_migrations")
+                .show(ui, |ui| {
+                    for migration in &report.migrations {
+                        ui.small(migration);
+                    }
+                });
+        }
+    }
+
+    fn paste(&self, ctx: &Context, text: &str) -> Toast {
+        match clipboard::paste(text, &ctx.style()) {
+            Ok((format, migrated)) => finish_import(
+                &self.imported,
+                &self.report,
+                Ok((migrated, None)),
+                &format!("Pasted {}", format.name()),
+            ),
+            Err(e) => Toast::error(format!("Paste Failed: {e}")),
+        }
+    }
+
+    /// Import a file dropped onto the window, like one picked with Import JSON.
+    pub fn import_dropped(&self, ctx: &Context, file: &DroppedFile, toasts_tx: &Sender<Toast>) {
+        // native drops have a path, web drops carry the bytes
+        let bytes = match (&file.path, &file.bytes) {
+            (_, Some(bytes)) => Ok(bytes.to_vec()),
+            (Some(path), None) => std::fs::read(path).map_err(|e| e.to_string()),
+            (None, None) => Err("the dropped file couldn't be read".to_owned()),
+        };
+        let name = match &file.path {
+            Some(path) => path.to_string_lossy().into_owned(),
+            None => file.name.clone(),
+        };
+
+        let result = bytes.and_then(|bytes| load(&name, &bytes, self.lenient, &ctx.style()));
+
+        #[cfg(not(target_arch = "wasm32"))]
+        if let (Ok(_), Some(path)) = (&result, &file.path) {
+            *self.watch.file().lock().unwrap() = Some(Watched::new(path.clone()));
+        }
+
+        let toast = finish_import(&self.imported, &self.report, result, "Import Successful");
+        toasts_tx.send(toast).unwrap();
+    }
+
+    /// Pick a theme file to revert to instead of egui's defaults, collected
+    /// with [`Self::take_baseline`].
+    pub fn load_baseline(&self, ctx: &Context, toasts_tx: Sender<Toast>) {
+        let lenient = self.lenient;
+        let baseline = self.baseline.clone();
+        let ctx = ctx.clone();
+        let task = rfd::AsyncFileDialog::new()
+            .add_filter("Theme file", &["json", "tokens", "rs"])
+            .pick_file();
+
+        crate::execute_future(async move {
+            let Some(file) = task.await else {
+                return;
+            };
+            let bytes = file.read().await;
+            let name = file.file_name();
+
+            match load(&name, &bytes, lenient, &ctx.style()) {
+                Ok((migrated, _)) => *baseline.lock().unwrap() = Some((name, migrated.style)),
+                Err(e) => {
+                    toasts_tx
+                        .send(Toast::error(format!("Loading Baseline Failed: {e}")))
+                        .unwrap();
+                }
+            }
+            ctx.request_repaint();
+        });
+    }
+
+    pub fn take_baseline(&self) -> Option<(String, Style)> {
+        self.baseline.lock().unwrap().take()
+    }
+
+    /// The style from the last successful import, for the caller to apply
+    /// alongside the state that mirrors it, and the notes to replace the
+    /// current ones with if it was a project.
+    pub fn take_imported(&self) -> Option<Imported> {
+        self.imported.lock().unwrap().take()
     }
 
     fn import(&self, toasts_tx: Sender<Toast>, ctx: Context) {
+        let lenient = self.lenient;
+        let report = self.report.clone();
+        let imported = self.imported.clone();
+        #[cfg(not(target_arch = "wasm32"))]
+        let watched = self.watch.file();
         let task = rfd::AsyncFileDialog::new()
-            .add_filter("JSON file", &["json"])
+            .add_filter("Theme file", &["json", "tokens", "rs"])
             .pick_file();
 
         crate::execute_future(async move {
             let file = task.await;
             if let Some(file) = file {
-                match serde_json::from_slice::<Style>(&file.read().await) {
-                    Ok(style) => {
-                        ctx.set_style(style);
-                        toasts_tx.send(Toast::info("Import Successful")).unwrap();
-                    }
-                    Err(e) => {
-                        toasts_tx
-                            .send(Toast::error(format!("Import Failed: {e}")))
-                            .unwrap();
-                    }
+                let bytes = file.read().await;
+                let result = load(&file.file_name(), &bytes, lenient, &ctx.style());
+
+                #[cfg(not(target_arch = "wasm32"))]
+                if result.is_ok() {
+                    *watched.lock().unwrap() = Some(Watched::new(file.path().to_owned()));
                 }
+
+                let toast = finish_import(&imported, &report, result, "Import Successful");
+                toasts_tx.send(toast).unwrap();
                 ctx.request_repaint();
             }
         });
     }
 }
+
+/// Read a theme file by its extension: Rust source from the exporter, or
+/// JSON that's either a project, design tokens or a `Style`. Only projects
+/// come with notes.
+fn load(
+    name: &str,
+    bytes: &[u8],
+    lenient: bool,
+    current: &Style,
+) -> Result<(Migrated, Option<Notes>), String> {
+    if name.ends_with(".rs") {
+        let text = std::str::from_utf8(bytes).map_err(|e| e.to_string())?;
+        return clipboard::paste(text, current).map(|(_, migrated)| (migrated, None));
+    }
+
+    let migrated = match serde_json::from_slice(bytes) {
+        Ok(document) if notes::is_project(&document) => {
+            return notes::load_project(bytes).map(|(migrated, notes)| (migrated, Some(notes)));
+        }
+        Ok(document) if design_tokens::is_design_tokens(&document) => {
+            Ok(design_tokens::import_tokens(&document, current))
+        }
+        _ if lenient => migrate::import_style_lenient(bytes, current),
+        _ => migrate::import_style(bytes),
+    };
+    migrated.map(|migrated| (migrated, None))
+}
+
+/// Keep an import's style for [`ImportMenu::take_imported`] and apply its
+/// report, returning a toast describing it, or `success` if nothing needed
+/// fixing.
+fn finish_import(
+    imported: &Mutex<Option<Imported>>,
+    report: &Mutex<Report>,
+    result: Result<(Migrated, Option<Notes>), String>,
+    success: &str,
+) -> Toast {
+    match result {
+        Ok((migrated, notes)) => {
+            *imported.lock().unwrap() = Some((migrated.style, notes));
+            let toast = match (migrated.skipped.len(), migrated.migrations.len()) {
+                (0, 0) => Toast::info(success),
+                (0, n) => {
+                    Toast::warning(format!("Imported an older theme, {n} migration(s) applied"))
+                }
+                (n, _) => Toast::warning(format!("Imported with {n} field(s) skipped")),
+            };
+            *report.lock().unwrap() = Report {
+                migrations: migrated.migrations,
+                skipped: migrated.skipped,
+            };
+            toast
+        }
+        Err(e) => Toast::error(format!("Import Failed: {e}")),
+    }
+}
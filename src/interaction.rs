@@ -1,35 +1,118 @@
 use eframe::egui::{style::Interaction, Ui};
 
 use crate::{
+    fields::Fields,
     pickers::{bool_picker, float_picker},
-    section_title,
+    section_title_reset,
 };
 
 #[derive(Copy, Clone, PartialEq, Eq, Default)]
 pub struct InteractionMenu;
 
 impl InteractionMenu {
-    pub fn ui(&mut self, ui: &mut Ui, interaction: &mut Interaction) {
-        let default = Interaction::default();
+    pub fn ui(&mut self, ui: &mut Ui, fields: &mut Fields, interaction: &mut Interaction) {
+        let default = fields
+            .baseline()
+            .map_or_else(Interaction::default, |style| style.interaction.clone());
 
-        ui.add(section_title(
-            "Interaction",
-            Some(crate::egui_doc_link!("/style/struct.Interaction.html")),
-        ));
-        ui.add(float_picker(
+        fields.section("Interaction");
+        if !fields.filtering()
+            && section_title_reset(
+                ui,
+                "Interaction",
+                Some(crate::egui_doc_link!("/style/struct.Interaction.html")),
+                *interaction != default,
+            )
+        {
+            *interaction = default.clone();
+            fields.bulk_edit();
+        }
+        fields.add(
+            ui,
+            "Interact Radius",
+            "interaction.interact_radius",
+            |title| {
+                float_picker(
+                    title,
+                    &mut interaction.interact_radius,
+                    default.interact_radius,
+                )
+            },
+        );
+        fields.add(
+            ui,
             "Resize Grab Radius - Side",
-            &mut interaction.resize_grab_radius_side,
-            default.resize_grab_radius_side,
-        ));
-        ui.add(float_picker(
+            "interaction.resize_grab_radius_side",
+            |title| {
+                float_picker(
+                    title,
+                    &mut interaction.resize_grab_radius_side,
+                    default.resize_grab_radius_side,
+                )
+            },
+        );
+        fields.add(
+            ui,
             "Resize Grab Radius - Corner",
-            &mut interaction.resize_grab_radius_corner,
-            default.resize_grab_radius_corner,
-        ));
-        ui.add(bool_picker(
+            "interaction.resize_grab_radius_corner",
+            |title| {
+                float_picker(
+                    title,
+                    &mut interaction.resize_grab_radius_corner,
+                    default.resize_grab_radius_corner,
+                )
+            },
+        );
+        fields.add(
+            ui,
             "Show Tooltips Only When Still",
-            &mut interaction.show_tooltips_only_when_still,
-            default.show_tooltips_only_when_still,
-        ));
+            "interaction.show_tooltips_only_when_still",
+            |title| {
+                bool_picker(
+                    title,
+                    &mut interaction.show_tooltips_only_when_still,
+                    default.show_tooltips_only_when_still,
+                )
+            },
+        );
+        fields.add(ui, "Tooltip Delay", "interaction.tooltip_delay", |title| {
+            float_picker(title, &mut interaction.tooltip_delay, default.tooltip_delay)
+        });
+        fields.add(
+            ui,
+            "Tooltip Grace Time",
+            "interaction.tooltip_grace_time",
+            |title| {
+                float_picker(
+                    title,
+                    &mut interaction.tooltip_grace_time,
+                    default.tooltip_grace_time,
+                )
+            },
+        );
+        fields.add(
+            ui,
+            "Selectable Labels",
+            "interaction.selectable_labels",
+            |title| {
+                bool_picker(
+                    title,
+                    &mut interaction.selectable_labels,
+                    default.selectable_labels,
+                )
+            },
+        );
+        fields.add(
+            ui,
+            "Multi Widget Text Select",
+            "interaction.multi_widget_text_select",
+            |title| {
+                bool_picker(
+                    title,
+                    &mut interaction.multi_widget_text_select,
+                    default.multi_widget_text_select,
+                )
+            },
+        );
     }
 }
@@ -0,0 +1,10 @@
+//! Theme serialization shared by the egui-themer app and its tests.
+
+pub mod clipboard;
+pub mod codegen;
+pub mod css;
+pub mod design_tokens;
+pub mod migrate;
+pub mod notes;
+pub mod share;
+pub mod tokens;
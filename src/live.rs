@@ -0,0 +1,117 @@
+use std::{
+    sync::{
+        mpsc::{self, Sender},
+        Arc, Mutex,
+    },
+    thread,
+};
+
+use eframe::{
+    egui::{Button, Layout, Style, TextEdit, Ui},
+    emath::Align,
+};
+use egui_themer_live::{Connection, DEFAULT_ADDR};
+
+/// Streams the style to an app using `egui-themer-live`, so a theme can be
+/// tuned while looking at the real thing.
+pub struct ConnectMenu {
+    addr: String,
+    link: Option<Link>,
+}
+
+struct Link {
+    styles_tx: Sender<Style>,
+    /// Why the connection ended, once it has.
+    error: Arc<Mutex<Option<String>>>,
+    /// The style last handed to the connection.
+    sent: Option<Style>,
+}
+
+impl Default for ConnectMenu {
+    fn default() -> Self {
+        Self {
+            addr: DEFAULT_ADDR.to_owned(),
+            link: None,
+        }
+    }
+}
+
+impl ConnectMenu {
+    pub fn ui(&mut self, ui: &mut Ui, style: &Style) {
+        ui.add(crate::section_title("Connect", None));
+
+        let error = self
+            .link
+            .as_ref()
+            .and_then(|link| link.error.lock().unwrap().clone());
+
+        ui.horizontal(|ui| {
+            ui.with_layout(Layout::right_to_left(Align::Min), |ui| {
+                let connected = self.link.is_some() && error.is_none();
+                if connected {
+                    if ui.button("Disconnect").clicked() {
+                        self.link = None;
+                    }
+                } else if ui
+                    .add_enabled(!self.addr.trim().is_empty(), Button::new("Connect"))
+                    .on_hover_text("Push every change to an app listening with egui-themer-live.")
+                    .clicked()
+                {
+                    self.link = Some(Link::connect(self.addr.trim().to_owned()));
+                }
+
+                ui.add_enabled(
+                    !connected,
+                    TextEdit::singleline(&mut self.addr)
+                        .hint_text(DEFAULT_ADDR)
+                        .desired_width(ui.available_width()),
+                );
+            });
+        });
+
+        match (&mut self.link, error) {
+            (Some(_), Some(error)) => {
+                ui.colored_label(ui.visuals().error_fg_color, format!("⚠ {error}"));
+            }
+            (Some(link), None) => {
+                ui.weak(format!("Pushing to {}", self.addr.trim()));
+                if link.sent.as_ref() != Some(style) {
+                    link.sent = Some(style.clone());
+                    // the error is picked up next frame if the thread is gone
+                    _ = link.styles_tx.send(style.clone());
+                }
+            }
+            (None, _) => {}
+        }
+    }
+}
+
+impl Link {
+    /// Connect on a background thread, which then sends every style it's
+    /// given, skipping to the latest if it falls behind.
+    fn connect(addr: String) -> Self {
+        let (styles_tx, styles_rx) = mpsc::channel::<Style>();
+        let error = Arc::new(Mutex::new(None));
+
+        let thread_error = error.clone();
+        thread::spawn(move || {
+            let result = Connection::connect(&addr).and_then(|mut connection| {
+                while let Ok(style) = styles_rx.recv() {
+                    let latest = styles_rx.try_iter().last().unwrap_or(style);
+                    connection.send(&latest)?;
+                }
+                Ok(())
+            });
+
+            if let Err(err) = result {
+                *thread_error.lock().unwrap() = Some(format!("Couldn't push to {addr}: {err}"));
+            }
+        });
+
+        Self {
+            styles_tx,
+            error,
+            sent: None,
+        }
+    }
+}
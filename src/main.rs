@@ -5,34 +5,52 @@ use std::{
 
 use eframe::{
     egui::{
-        Context, Frame, Layout, Margin, Response, RichText, ScrollArea, SidePanel, Style, Ui,
-        Visuals, Widget,
+        Button, Color32, Context, Frame, Id, Key, KeyboardShortcut, LayerId, Layout, Margin,
+        Modifiers, Order, Response, RichText, ScrollArea, SidePanel, Style, TextStyle, Ui, Visuals,
+        Widget,
     },
-    emath::Align,
+    emath::{Align, Align2},
+    CreationContext,
 };
 use egui_demo_lib::DemoWindows;
 use egui_notify::{Toast, Toasts};
 use export::ExportMenu;
+use fields::Fields;
+use gallery::Gallery;
 use import::ImportMenu;
 use interaction::InteractionMenu;
 use misc::MiscMenu;
+use palette::{Command, CommandPalette};
+use project::ProjectMenu;
 use spacing::SpacingMenu;
 use visuals::VisualsMenu;
 
+#[cfg(not(target_arch = "wasm32"))]
+mod check;
 mod export;
+mod fields;
+mod gallery;
 mod import;
 mod interaction;
+#[cfg(not(target_arch = "wasm32"))]
+mod live;
 mod misc;
+mod palette;
 mod pickers;
+mod project;
 mod spacing;
 mod visuals;
+#[cfg(not(target_arch = "wasm32"))]
+mod watch;
+#[cfg(not(target_arch = "wasm32"))]
+mod write_back;
 
 #[cfg(not(target_arch = "wasm32"))]
 fn main() {
     eframe::run_native(
         "Egui Themer",
         eframe::NativeOptions::default(),
-        Box::new(|_| Ok(Box::new(Themer::new()))),
+        Box::new(|cc| Ok(Box::new(Themer::new(cc)))),
     )
     .expect("run eframe native app");
 }
@@ -44,7 +62,7 @@ fn main() {
             .start(
                 "app",
                 eframe::WebOptions::default(),
-                Box::new(|_| Ok(Box::new(Themer::new()))),
+                Box::new(|cc| Ok(Box::new(Themer::new(cc)))),
             )
             .await
             .expect("failed to start eframe");
@@ -58,30 +76,75 @@ struct Themer {
 
     import: ImportMenu,
     export: ExportMenu,
+    #[cfg(not(target_arch = "wasm32"))]
+    live: live::ConnectMenu,
+
+    fields: Fields,
+    palette: CommandPalette,
+    project: ProjectMenu,
 
     visuals: VisualsMenu,
     misc: MiscMenu,
     spacing: SpacingMenu,
     interaction: InteractionMenu,
 
+    preview: Preview,
     demo: DemoWindows,
+    gallery: Gallery,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Preview {
+    Demo,
+    Gallery,
 }
 
 impl Themer {
-    fn new() -> Self {
+    fn new(cc: &CreationContext) -> Self {
         let (toasts_tx, toasts_rx) = mpsc::channel();
 
+        #[cfg(not(target_arch = "wasm32"))]
+        let share_url = egui_themer::share::LIVE_URL.to_owned();
+        #[cfg(target_arch = "wasm32")]
+        let share_url = {
+            let location = &cc.integration_info.web_info.location;
+            if location.hash.contains(egui_themer::share::FRAGMENT_KEY) {
+                match egui_themer::share::decode(&location.hash) {
+                    Ok(migrated) => cc.egui_ctx.set_style(migrated.style),
+                    Err(err) => {
+                        toasts_tx
+                            .send(Toast::error(format!(
+                                "Couldn't load the shared theme: {err}"
+                            )))
+                            .unwrap();
+                    }
+                }
+            }
+            location.url.clone()
+        };
+
+        // a shared theme may have changed the roundings before the pickers exist
+        let mut visuals = VisualsMenu::default();
+        visuals.sync_roundings(&cc.egui_ctx.style().visuals);
+
         Self {
             toasts: Default::default(),
             toasts_tx,
             toasts_rx,
             import: Default::default(),
-            export: Default::default(),
-            visuals: Default::default(),
+            export: ExportMenu::new(share_url),
+            #[cfg(not(target_arch = "wasm32"))]
+            live: Default::default(),
+            fields: Default::default(),
+            palette: Default::default(),
+            project: Default::default(),
+            visuals,
             misc: Default::default(),
             spacing: Default::default(),
             interaction: Default::default(),
+            preview: Preview::Demo,
             demo: Default::default(),
+            gallery: Default::default(),
         }
     }
 }
@@ -93,6 +156,29 @@ impl eframe::App for Themer {
             ctx.request_repaint();
         }
 
+        if let Some(command) = self.palette.ui(ctx) {
+            self.run(ctx, command);
+        }
+        palette::coarse_nudge(ctx, &self.fields);
+
+        for file in ctx.input(|i| i.raw.dropped_files.clone()) {
+            self.import.import_dropped(ctx, &file, &self.toasts_tx);
+        }
+
+        if ctx.input(|i| !i.raw.hovered_files.is_empty()) {
+            let painter =
+                ctx.layer_painter(LayerId::new(Order::Foreground, Id::new("drop_target")));
+            let screen = ctx.screen_rect();
+            painter.rect_filled(screen, 0.0, Color32::from_black_alpha(192));
+            painter.text(
+                screen.center(),
+                Align2::CENTER_CENTER,
+                "Drop a theme file to import it",
+                TextStyle::Heading.resolve(&ctx.style()),
+                Color32::WHITE,
+            );
+        }
+
         SidePanel::left("themer_side_panel")
             .min_width(370.0)
             .max_width(ctx.available_rect().width() / 2.0)
@@ -125,41 +211,202 @@ impl eframe::App for Themer {
                             });
                             self.visuals = VisualsMenu::default();
                         }
+
+                        if let Some(baseline) = self.fields.baseline() {
+                            if ui.button("Baseline").clicked() {
+                                ctx.set_style(baseline.clone());
+                                self.visuals.sync_roundings(&baseline.visuals);
+                            }
+                        }
+                    });
+                });
+
+                if let Some(baseline) = self.import.take_baseline() {
+                    self.fields.set_baseline(Some(baseline));
+                }
+
+                ui.horizontal(|ui| {
+                    ui.label("Baseline").on_hover_text(
+                        "The style ⟲ buttons reset to, instead of egui's defaults.",
+                    );
+                    ui.with_layout(Layout::right_to_left(Align::Min), |ui| {
+                        if ui
+                            .add_enabled(self.fields.baseline().is_some(), Button::new("🗙"))
+                            .on_hover_text("Go back to egui's defaults")
+                            .clicked()
+                        {
+                            self.fields.set_baseline(None);
+                        }
+                        if ui.button("Load…").clicked() {
+                            self.import.load_baseline(ctx, self.toasts_tx.clone());
+                        }
+                        if ui.button("Use Current").clicked() {
+                            self.fields
+                                .set_baseline(Some(("current style".to_owned(), (*ctx.style()).clone())));
+                        }
+                        ui.weak(self.fields.baseline_name().unwrap_or("egui defaults"));
+                    });
+                });
+
+                if let Some((style, notes)) = self.project.take_opened() {
+                    self.visuals.sync_roundings(&style.visuals);
+                    ctx.set_style(style);
+                    self.fields.set_notes(notes);
+                }
+                self.project.ui(
+                    ui,
+                    &ctx.style(),
+                    self.fields.notes(),
+                    self.toasts_tx.clone(),
+                );
+
+                ui.horizontal(|ui| {
+                    ui.label("Commands");
+                    ui.with_layout(Layout::right_to_left(Align::Min), |ui| {
+                        let shortcut =
+                            ctx.format_shortcut(&KeyboardShortcut::new(Modifiers::COMMAND, Key::P));
+                        if ui
+                            .button(shortcut)
+                            .on_hover_text(
+                                "Search every command. PageUp and PageDown move between fields, \
+                                 Shift+↑/↓ nudge a focused value ten steps.",
+                            )
+                            .clicked()
+                        {
+                            self.palette.toggle();
+                        }
+                    });
+                });
+
+                ui.horizontal(|ui| {
+                    ui.label("Preview");
+                    ui.with_layout(Layout::right_to_left(Align::Min), |ui| {
+                        ui.selectable_value(&mut self.preview, Preview::Gallery, "Gallery");
+                        ui.selectable_value(&mut self.preview, Preview::Demo, "Demo Windows");
                     });
                 });
 
                 ui.separator();
 
                 self.import.ui(ui, ctx, self.toasts_tx.clone());
+                if let Some((style, notes)) = self.import.take_imported() {
+                    self.visuals.sync_roundings(&style.visuals);
+                    ctx.set_style(style);
+                    if let Some(notes) = notes {
+                        self.fields.set_notes(notes);
+                    }
+                }
                 ui.separator();
 
                 let mut style = (*ctx.style()).clone();
+                if self.fields.restore_locked(&mut style) {
+                    // or the rounding pickers would put their uniform values back
+                    self.visuals.sync_roundings(&style.visuals);
+                }
 
-                self.export.ui(ui, &style, &mut self.toasts);
+                self.export
+                    .ui(ui, &style, self.fields.notes(), &mut self.toasts);
                 ui.separator();
 
-                ScrollArea::both().show(ui, |ui| {
-                    self.visuals.ui(ui, &mut style.visuals);
+                #[cfg(not(target_arch = "wasm32"))]
+                {
+                    self.live.ui(ui, &style);
                     ui.separator();
+                }
 
-                    self.spacing.ui(ui, &mut style.spacing);
-                    ui.separator();
+                self.fields.filter_ui(ui);
 
-                    self.interaction.ui(ui, &mut style.interaction);
-                    ui.separator();
+                ScrollArea::both().show(ui, |ui| {
+                    let filtering = self.fields.filtering();
 
-                    self.misc.ui(ui, &mut style);
+                    self.visuals.ui(ui, &mut self.fields, &mut style.visuals);
+                    if !filtering {
+                        ui.separator();
+                    }
+
+                    self.spacing.ui(ui, &mut self.fields, &mut style.spacing);
+                    if !filtering {
+                        ui.separator();
+                    }
+
+                    self.interaction
+                        .ui(ui, &mut self.fields, &mut style.interaction);
+                    if !filtering {
+                        ui.separator();
+                    }
+
+                    self.misc.ui(ui, &mut self.fields, &mut style);
+
+                    if filtering && self.fields.matches() == 0 {
+                        ui.label("No matching fields");
+                    }
                 });
 
+                if self.fields.finish_locked(&mut style) {
+                    self.visuals.sync_roundings(&style.visuals);
+                }
                 ctx.set_style(style);
             });
 
-        self.demo.ui(ctx);
+        match self.preview {
+            Preview::Demo => self.demo.ui(ctx),
+            Preview::Gallery => {
+                if let Some(path) = self.gallery.ui(ctx) {
+                    self.fields.inspect(&path);
+                    self.visuals.inspect(&path);
+                    ctx.request_repaint();
+                }
+            }
+        }
 
         self.toasts.show(ctx);
     }
 }
 
+impl Themer {
+    fn run(&mut self, ctx: &Context, command: Command) {
+        let mut style = (*ctx.style()).clone();
+        let default = self.fields.baseline().cloned().unwrap_or_else(|| Style {
+            visuals: if style.visuals.dark_mode {
+                Visuals::dark()
+            } else {
+                Visuals::light()
+            },
+            ..Default::default()
+        });
+
+        match command {
+            Command::ResetStyle { dark } => {
+                style = Style {
+                    visuals: if dark {
+                        Visuals::dark()
+                    } else {
+                        Visuals::light()
+                    },
+                    ..Default::default()
+                };
+                self.visuals = VisualsMenu::default();
+            }
+            Command::ResetSection(section) => {
+                section.copy(&default, &mut style);
+                self.visuals.sync_roundings(&style.visuals);
+            }
+            Command::ToggleDarkMode => style.visuals.dark_mode = !style.visuals.dark_mode,
+            Command::ShowTab(tab) => self.visuals.show_tab(&tab),
+            Command::FocusSearch => self.fields.focus_search(),
+            Command::ShowGallery => self.preview = Preview::Gallery,
+            Command::ShowDemo => self.preview = Preview::Demo,
+            Command::Export => {
+                self.export
+                    .export(ctx, &style, self.fields.notes(), &mut self.toasts)
+            }
+            Command::CopyShareLink => self.export.copy_share_link(ctx, &style, &mut self.toasts),
+        }
+
+        ctx.set_style(style);
+    }
+}
+
 pub fn section_title<'a>(name: &'a str, url: Option<&'a str>) -> impl Widget + 'a {
     move |ui: &mut Ui| {
         ui.add_space(3.0);
@@ -176,6 +423,21 @@ pub fn section_title<'a>(name: &'a str, url: Option<&'a str>) -> impl Widget + '
     }
 }
 
+/// A [`section_title`] with a ⟲ button, enabled while the section differs
+/// from the baseline. Returns true when the button is clicked.
+pub fn section_title_reset(ui: &mut Ui, name: &str, url: Option<&str>, changed: bool) -> bool {
+    ui.horizontal(|ui| {
+        ui.add(section_title(name, url));
+        ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+            ui.add_enabled(changed, Button::new("⟲"))
+                .on_hover_text(format!("Reset {name} to the baseline"))
+                .clicked()
+        })
+        .inner
+    })
+    .inner
+}
+
 pub fn picker_frame(ui: &mut Ui, show: impl Widget) -> Response {
     let style = ui.style();
 
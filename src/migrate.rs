@@ -0,0 +1,255 @@
+//! Upgrading `Style` JSON written by older egui versions to the current schema.
+
+use eframe::egui::{Style, Visuals};
+use serde_json::{json, Map, Value};
+
+/// A style read from JSON, along with every change made to get it there.
+pub struct Migrated {
+    pub style: Style,
+    pub migrations: Vec<String>,
+    /// Fields left at their current value by a lenient import.
+    pub skipped: Vec<SkippedField>,
+}
+
+/// A field a lenient import couldn't load.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SkippedField {
+    /// Dotted path to the field, e.g. `visuals.window_shadow.blur`.
+    pub path: String,
+    pub reason: String,
+}
+
+/// Parse `Style` JSON from any egui version since 0.19, renaming and
+/// reshaping old fields and filling the ones it lacks from the defaults.
+pub fn import_style(bytes: &[u8]) -> Result<Migrated, String> {
+    let mut value: Value = serde_json::from_slice(bytes).map_err(|e| e.to_string())?;
+    let mut migrations = migrate(&mut value)?;
+
+    let defaults = serde_json::to_value(default_style(&value)).map_err(|e| e.to_string())?;
+    if let Some(style) = value.as_object_mut() {
+        fill_defaults(style, &defaults, "", &mut migrations);
+    }
+
+    let style = serde_json::from_value(value).map_err(|e| e.to_string())?;
+
+    Ok(Migrated {
+        style,
+        migrations,
+        skipped: Vec::new(),
+    })
+}
+
+/// Like [`import_style`], but loads every field it can on top of `current`
+/// instead of failing on the first bad one. Unknown and malformed fields
+/// keep their current value and are listed in [`Migrated::skipped`].
+pub fn import_style_lenient(bytes: &[u8], current: &Style) -> Result<Migrated, String> {
+    let mut value: Value = serde_json::from_slice(bytes).map_err(|e| e.to_string())?;
+    let migrations = migrate(&mut value)?;
+
+    let Value::Object(imported) = value else {
+        unreachable!("migrate only accepts objects");
+    };
+
+    let mut base = serde_json::to_value(current).map_err(|e| e.to_string())?;
+    let mut skipped = Vec::new();
+    merge_lenient(&mut base, "", "", imported, &mut skipped);
+
+    let style = serde_json::from_value(base).map_err(|e| e.to_string())?;
+
+    Ok(Migrated {
+        style,
+        migrations,
+        skipped,
+    })
+}
+
+/// Upgrade the shape of `value` in place, returning a description of each change.
+pub fn migrate(value: &mut Value) -> Result<Vec<String>, String> {
+    // notes exported beside the fields aren't part of the style
+    crate::notes::strip_comments(value);
+
+    let style = value
+        .as_object_mut()
+        .ok_or("expected a JSON object at the top level")?;
+    let mut migrations = Vec::new();
+
+    // egui 0.24 moved the scroll bar sizes into `spacing.scroll`
+    if let Some(spacing) = style.get_mut("spacing").and_then(Value::as_object_mut) {
+        for (old, new) in [
+            ("scroll_bar_width", "bar_width"),
+            ("scroll_handle_min_length", "handle_min_length"),
+            ("scroll_bar_inner_margin", "bar_inner_margin"),
+            ("scroll_bar_outer_margin", "bar_outer_margin"),
+        ] {
+            if let Some(field) = spacing.remove(old) {
+                object_entry(spacing, "scroll").insert(new.to_owned(), field);
+                migrations.push(format!("moved spacing.{old} to spacing.scroll.{new}"));
+            }
+        }
+    }
+
+    if let Some(visuals) = style.get_mut("visuals").and_then(Value::as_object_mut) {
+        // egui 0.27 replaced the shadow extrusion with an offset, blur and spread
+        for field in ["window_shadow", "popup_shadow"] {
+            let Some(shadow) = visuals.get_mut(field).and_then(Value::as_object_mut) else {
+                continue;
+            };
+            if let Some(extrusion) = shadow.remove("extrusion") {
+                shadow.insert("offset".to_owned(), json!({ "x": 0.0, "y": 0.0 }));
+                shadow.insert("blur".to_owned(), extrusion);
+                shadow.insert("spread".to_owned(), json!(0.0));
+                migrations.push(format!(
+                    "converted visuals.{field}.extrusion to visuals.{field}.blur"
+                ));
+            }
+        }
+
+        // egui 0.28 grouped the text cursor stroke and preview into `TextCursorStyle`
+        let preview = visuals.remove("text_cursor_preview");
+        if let Some(cursor) = visuals.get_mut("text_cursor") {
+            if cursor.get("width").is_some() {
+                *cursor = json!({ "stroke": cursor.take() });
+                migrations.push("moved visuals.text_cursor to visuals.text_cursor.stroke".into());
+
+                if let Some(preview) = preview {
+                    cursor["preview"] = preview;
+                    migrations.push(
+                        "moved visuals.text_cursor_preview to visuals.text_cursor.preview".into(),
+                    );
+                }
+            }
+        }
+    }
+
+    // egui 0.28 replaced `wrap: Option<bool>` with `wrap_mode`
+    if let Some(wrap) = style.remove("wrap") {
+        if let Some(wrap) = wrap.as_bool() {
+            let mode = if wrap { "Wrap" } else { "Extend" };
+            style.insert("wrap_mode".to_owned(), json!(mode));
+            migrations.push(format!("converted wrap: {wrap} to wrap_mode: {mode}"));
+        }
+    }
+
+    Ok(migrations)
+}
+
+/// The defaults matching the light or dark mode of the imported style.
+fn default_style(value: &Value) -> Style {
+    let dark_mode = value
+        .pointer("/visuals/dark_mode")
+        .and_then(Value::as_bool)
+        .unwrap_or(true);
+
+    Style {
+        visuals: if dark_mode {
+            Visuals::dark()
+        } else {
+            Visuals::light()
+        },
+        ..Default::default()
+    }
+}
+
+fn object_entry<'a>(object: &'a mut Map<String, Value>, key: &str) -> &'a mut Map<String, Value> {
+    let entry = object
+        .entry(key)
+        .or_insert_with(|| Value::Object(Map::new()));
+    if !entry.is_object() {
+        *entry = Value::Object(Map::new());
+    }
+    entry.as_object_mut().unwrap()
+}
+
+/// Copy every field missing from `object` over from `defaults`, recursing
+/// into nested structs. Fields defaulting to `null` are left to serde.
+fn fill_defaults(
+    object: &mut Map<String, Value>,
+    defaults: &Value,
+    path: &str,
+    migrations: &mut Vec<String>,
+) {
+    let Some(defaults) = defaults.as_object() else {
+        return;
+    };
+
+    for (key, default) in defaults {
+        if default.is_null() {
+            continue;
+        }
+
+        let field = if path.is_empty() {
+            key.clone()
+        } else {
+            format!("{path}.{key}")
+        };
+
+        match object.get_mut(key) {
+            Some(Value::Object(nested)) => fill_defaults(nested, default, &field, migrations),
+            Some(_) => (),
+            None => {
+                object.insert(key.clone(), default.clone());
+                migrations.push(format!("filled missing {field} from the defaults"));
+            }
+        }
+    }
+}
+
+/// Copy each field of `imported` into `base` one at a time, keeping only the
+/// ones that still deserialize into a `Style` and survive the round trip.
+fn merge_lenient(
+    base: &mut Value,
+    pointer: &str,
+    path: &str,
+    imported: Map<String, Value>,
+    skipped: &mut Vec<SkippedField>,
+) {
+    for (key, value) in imported {
+        let field = if path.is_empty() {
+            key.clone()
+        } else {
+            format!("{path}.{key}")
+        };
+        let field_pointer = format!("{pointer}/{}", key.replace('~', "~0").replace('/', "~1"));
+
+        let value = match value {
+            Value::Object(nested) if base.pointer(&field_pointer).is_some_and(Value::is_object) => {
+                merge_lenient(base, &field_pointer, &field, nested, skipped);
+                continue;
+            }
+            value => value,
+        };
+
+        let parent = base
+            .pointer_mut(pointer)
+            .and_then(Value::as_object_mut)
+            .expect("parent is an object");
+        let previous = parent.insert(key.clone(), value);
+
+        let reason = match serde_json::from_value::<Style>(base.clone()) {
+            Err(err) => err.to_string(),
+            Ok(style) => {
+                let loaded = serde_json::to_value(style)
+                    .ok()
+                    .is_some_and(|loaded| loaded.pointer(&field_pointer).is_some());
+                if loaded {
+                    continue;
+                }
+                "unknown field".to_owned()
+            }
+        };
+
+        let parent = base
+            .pointer_mut(pointer)
+            .and_then(Value::as_object_mut)
+            .expect("parent is an object");
+        match previous {
+            Some(previous) => parent.insert(key, previous),
+            None => parent.remove(&key),
+        };
+
+        skipped.push(SkippedField {
+            path: field,
+            reason,
+        });
+    }
+}
@@ -1,11 +1,12 @@
 use crate::{
+    fields::Fields,
     picker_frame,
-    pickers::{bool_picker, float_picker},
-    section_title,
+    pickers::{bool_picker, enum_picker, float_picker},
+    section_title_reset,
 };
 use eframe::egui::TextWrapMode;
 use eframe::{
-    egui::{Button, ComboBox, Layout, Style, Ui},
+    egui::{Layout, Style, Ui},
     emath::Align,
 };
 
@@ -13,87 +14,91 @@ use eframe::{
 pub struct MiscMenu;
 
 impl MiscMenu {
-    pub fn ui(&mut self, ui: &mut Ui, style: &mut Style) {
-        ui.add(section_title(
-            "Miscellaneous",
-            Some(crate::egui_doc_link!("/style/struct.Style.html")),
-        ));
+    pub fn ui(&mut self, ui: &mut Ui, fields: &mut Fields, style: &mut Style) {
+        let default = fields.baseline().cloned().unwrap_or_default();
+        let reset = |style: &mut Style| {
+            style.animation_time = default.animation_time;
+            style.explanation_tooltips = default.explanation_tooltips;
+            style.url_in_tooltip = default.url_in_tooltip;
+            style.always_scroll_the_only_direction = default.always_scroll_the_only_direction;
+            style.wrap_mode = default.wrap_mode;
+        };
 
-        ui.add(float_picker(
-            "Animation Time",
-            &mut style.animation_time,
-            Style::default().animation_time,
-        ));
-        ui.add(bool_picker(
-            "Explanation Tooltips",
-            &mut style.explanation_tooltips,
-            Style::default().explanation_tooltips,
-        ));
-        wrap_picker(ui, &mut style.wrap_mode);
-        picker_frame(ui, |ui: &mut Ui| {
-            ui.horizontal(|ui| {
-                ui.label("Text Styles");
-                ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
-                    ui.label("Edit Text Styles Manually in Export")
-                });
-            })
-            .response
-        });
-    }
-}
+        fields.section("Miscellaneous");
+        if !fields.filtering() {
+            let mut reset_style = style.clone();
+            reset(&mut reset_style);
 
-fn wrap_picker(ui: &mut Ui, wrap_mode: &mut Option<TextWrapMode>) {
-    let mut wrap = wrap_mode
-        .iter()
-        .map(|x| match x {
-            TextWrapMode::Extend => false,
-            TextWrapMode::Wrap => true,
-            TextWrapMode::Truncate => false,
-        })
-        .next();
-    ui.add(|ui: &mut Ui| {
-        picker_frame(ui, |ui: &mut Ui| {
-            ui.horizontal(|ui| {
-                ui.label("Wrap");
-                ui.with_layout(Layout::right_to_left(Align::Min), |ui| {
-                    if ui.add_enabled(wrap.is_some(), Button::new("⟲")).clicked() {
-                        wrap = None;
-                    }
+            if section_title_reset(
+                ui,
+                "Miscellaneous",
+                Some(crate::egui_doc_link!("/style/struct.Style.html")),
+                reset_style != *style,
+            ) {
+                reset(style);
+                fields.bulk_edit();
+            }
+        }
 
-                    ComboBox::new("Wrap", "")
-                        .selected_text(match wrap {
-                            None => "Follow Layout",
-                            Some(true) => "Default On",
-                            Some(false) => "Default Off",
-                        })
-                        .show_ui(ui, |ui| {
-                            let mut changed = false;
-                            changed |= ui
-                                .selectable_value(&mut wrap, None, "None - Follow Layout")
-                                .changed();
-                            changed |= ui
-                                .selectable_value(&mut wrap, Some(true), "Some(true) - Default On")
-                                .changed();
-                            changed |= ui
-                                .selectable_value(
-                                    &mut wrap,
-                                    Some(false),
-                                    "Some(false) - Default Off",
-                                )
-                                .changed();
-                            if changed {
-                                *wrap_mode = wrap.map(|wrap| {
-                                    if wrap {
-                                        TextWrapMode::Wrap
-                                    } else {
-                                        TextWrapMode::Truncate
-                                    }
-                                });
-                            }
+        fields.add(ui, "Animation Time", "animation_time", |title| {
+            float_picker(title, &mut style.animation_time, default.animation_time)
+        });
+        fields.add(
+            ui,
+            "Explanation Tooltips",
+            "explanation_tooltips",
+            |title| {
+                bool_picker(
+                    title,
+                    &mut style.explanation_tooltips,
+                    default.explanation_tooltips,
+                )
+            },
+        );
+        fields.add(ui, "URL In Tooltip", "url_in_tooltip", |title| {
+            bool_picker(title, &mut style.url_in_tooltip, default.url_in_tooltip)
+        });
+        fields.add(
+            ui,
+            "Always Scroll The Only Direction",
+            "always_scroll_the_only_direction",
+            |title| {
+                bool_picker(
+                    title,
+                    &mut style.always_scroll_the_only_direction,
+                    default.always_scroll_the_only_direction,
+                )
+            },
+        );
+        fields.add(ui, "Wrap Mode", "wrap_mode", |title| {
+            enum_picker(
+                title,
+                &mut style.wrap_mode,
+                default.wrap_mode,
+                &[
+                    None,
+                    Some(TextWrapMode::Extend),
+                    Some(TextWrapMode::Wrap),
+                    Some(TextWrapMode::Truncate),
+                ],
+                |wrap_mode| match wrap_mode {
+                    None => "None - Follow Layout".to_owned(),
+                    Some(wrap_mode) => format!("{wrap_mode:?}"),
+                },
+            )
+        });
+        fields.add(ui, "Text Styles", "text_styles", |title| {
+            move |ui: &mut Ui| {
+                picker_frame(ui, |ui: &mut Ui| {
+                    ui.horizontal(|ui| {
+                        ui.label(title);
+                        ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                            ui.label("Edit Text Styles Manually in Export")
                         });
-                });
-            })
-            .response
-        })
-    });
+                    })
+                    .response
+                })
+            }
+        });
+    }
 }
@@ -0,0 +1,159 @@
+//! Free-text notes on `Style` fields, saved in a project file alongside the
+//! style and carried into exports as comments.
+
+use std::collections::BTreeMap;
+
+use eframe::egui::Style;
+use serde_json::{json, Map, Value};
+
+use crate::migrate::{self, Migrated};
+
+/// Notes keyed by dotted field path, e.g. `visuals.widgets.hovered.bg_fill`.
+pub type Notes = BTreeMap<String, String>;
+
+/// The key JSON exports keep the notes of an object's fields under.
+pub const COMMENT_KEY: &str = "$comment";
+
+/// The key marking a project file, holding its format version.
+const PROJECT_KEY: &str = "egui_themer_project";
+
+/// Write `style` and its `notes` as a project file.
+pub fn save_project(style: &Style, notes: &Notes) -> Result<String, String> {
+    serde_json::to_string_pretty(&json!({
+        PROJECT_KEY: 1,
+        "style": style,
+        "notes": notes,
+    }))
+    .map_err(|e| e.to_string())
+}
+
+/// Whether `value` is a project file written by [`save_project`].
+pub fn is_project(value: &Value) -> bool {
+    value.get(PROJECT_KEY).is_some()
+}
+
+/// Read a project file, migrating its style like [`migrate::import_style`].
+pub fn load_project(bytes: &[u8]) -> Result<(Migrated, Notes), String> {
+    let mut project: Value = serde_json::from_slice(bytes).map_err(|e| e.to_string())?;
+    if !is_project(&project) {
+        return Err("not an egui-themer project".to_owned());
+    }
+
+    let style = serde_json::to_vec(&project["style"].take()).map_err(|e| e.to_string())?;
+    let migrated = migrate::import_style(&style)?;
+    let notes = match project["notes"].take() {
+        Value::Null => Notes::new(),
+        notes => serde_json::from_value(notes).map_err(|e| format!("notes: {e}"))?,
+    };
+
+    Ok((migrated, notes))
+}
+
+/// Add each note as `//` comments above its field in Rust generated by
+/// [`crate::codegen`]. `prefix` is the path of the struct the source builds,
+/// empty for a whole `Style`.
+pub fn annotate_rust(source: &str, prefix: &str, notes: &Notes) -> String {
+    if notes.is_empty() {
+        return source.to_owned();
+    }
+
+    let mut annotated = String::with_capacity(source.len());
+    // the field each open brace belongs to, if it belongs to one
+    let mut open: Vec<Option<&str>> = Vec::new();
+
+    for line in source.split_inclusive('\n') {
+        let trimmed = line.trim_start();
+        // fields the templates leave commented out, like `text_styles`, can
+        // be noted too
+        let field = field_name(trimmed).or_else(|| {
+            let commented = trimmed.strip_prefix("//")?;
+            field_name(commented.trim_start())
+        });
+
+        if let Some(field) = field {
+            let path: Vec<&str> = (!prefix.is_empty())
+                .then_some(prefix)
+                .into_iter()
+                .chain(open.iter().flatten().copied())
+                .chain([field])
+                .collect();
+
+            if let Some(note) = notes.get(&path.join(".")) {
+                let indent = &line[..line.len() - trimmed.len()];
+                for note in note.lines() {
+                    match note.trim_end() {
+                        "" => annotated.push_str(&format!("{indent}//\n")),
+                        note => annotated.push_str(&format!("{indent}// {note}\n")),
+                    }
+                }
+            }
+        }
+        annotated.push_str(line);
+
+        if trimmed.starts_with("//") {
+            continue;
+        }
+        let opened = trimmed.matches('{').count();
+        let closed = trimmed.matches('}').count();
+        if opened > closed {
+            open.push(field);
+            open.extend(std::iter::repeat_n(None, opened - closed - 1));
+        } else {
+            open.truncate(open.len().saturating_sub(closed - opened));
+        }
+    }
+
+    annotated
+}
+
+/// The field a line of a struct literal sets, like `bg_fill` in
+/// `bg_fill: Color32::from_rgba_premultiplied(..),`.
+fn field_name(line: &str) -> Option<&str> {
+    let end = line.find(|c: char| !(c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_'))?;
+    let rest = &line[end..];
+    (end > 0 && rest.starts_with(':') && !rest.starts_with("::")).then(|| &line[..end])
+}
+
+/// Add each note to `value`, the JSON of the struct at `prefix`, under a
+/// [`COMMENT_KEY`] object beside its field.
+pub fn annotate_json(value: &mut Value, prefix: &str, notes: &Notes) {
+    for (path, note) in notes {
+        let path = match prefix {
+            "" => path.as_str(),
+            prefix => match path.strip_prefix(prefix).and_then(|p| p.strip_prefix('.')) {
+                Some(path) => path,
+                None => continue,
+            },
+        };
+        let (parent, field) = path.rsplit_once('.').unwrap_or(("", path));
+        let pointer = match parent {
+            "" => String::new(),
+            parent => format!("/{}", parent.replace('.', "/")),
+        };
+
+        let Some(Value::Object(parent)) = value.pointer_mut(&pointer) else {
+            continue;
+        };
+        if !parent.contains_key(field) {
+            continue;
+        }
+        if let Value::Object(comments) = parent
+            .entry(COMMENT_KEY)
+            .or_insert_with(|| Value::Object(Map::new()))
+        {
+            comments.insert(field.to_owned(), Value::String(note.clone()));
+        }
+    }
+}
+
+/// Remove every [`COMMENT_KEY`] object added by [`annotate_json`].
+pub fn strip_comments(value: &mut Value) {
+    match value {
+        Value::Object(object) => {
+            object.remove(COMMENT_KEY);
+            object.values_mut().for_each(strip_comments);
+        }
+        Value::Array(array) => array.iter_mut().for_each(strip_comments),
+        _ => {}
+    }
+}
@@ -0,0 +1,199 @@
+use eframe::{
+    egui::{Context, Event, Key, Modifiers, ScrollArea, TextEdit, Window},
+    emath::Align2,
+};
+use egui_themer::codegen::Section;
+
+use crate::fields::Fields;
+
+/// Ctrl+P opens a searchable list of commands, run with Enter.
+#[derive(Default)]
+pub struct CommandPalette {
+    open: bool,
+    query: String,
+    /// Index of the highlighted command among those matching `query`.
+    selected: usize,
+}
+
+/// Something the palette can do, run by `Themer`.
+#[derive(Clone, PartialEq)]
+pub enum Command {
+    ResetStyle {
+        dark: bool,
+    },
+    ResetSection(Section),
+    ToggleDarkMode,
+    /// Switch to a Visuals tab named by `VisualsMenu::tab_names`.
+    ShowTab(String),
+    FocusSearch,
+    ShowGallery,
+    ShowDemo,
+    Export,
+    CopyShareLink,
+}
+
+impl Command {
+    /// Every command, with the name it's listed and searched by.
+    pub fn all() -> Vec<(String, Command)> {
+        let mut commands = vec![
+            (
+                "Reset to Default Dark".to_owned(),
+                Command::ResetStyle { dark: true },
+            ),
+            (
+                "Reset to Default Light".to_owned(),
+                Command::ResetStyle { dark: false },
+            ),
+            ("Toggle Dark Mode".to_owned(), Command::ToggleDarkMode),
+        ];
+
+        for section in Section::ALL {
+            commands.push((
+                format!("Reset {}", section.type_name()),
+                Command::ResetSection(section),
+            ));
+        }
+
+        for tab in crate::visuals::VisualsMenu::tab_names() {
+            commands.push((format!("Go to Visuals › {tab}"), Command::ShowTab(tab)));
+        }
+
+        commands.extend([
+            ("Search Fields".to_owned(), Command::FocusSearch),
+            ("Preview Gallery".to_owned(), Command::ShowGallery),
+            ("Preview Demo Windows".to_owned(), Command::ShowDemo),
+            ("Export".to_owned(), Command::Export),
+            ("Copy Share Link".to_owned(), Command::CopyShareLink),
+        ]);
+
+        commands
+    }
+}
+
+impl CommandPalette {
+    pub fn toggle(&mut self) {
+        self.open = !self.open;
+        self.query.clear();
+        self.selected = 0;
+    }
+
+    /// Show the palette if it's open, returning the command picked.
+    pub fn ui(&mut self, ctx: &Context) -> Option<Command> {
+        if ctx.input_mut(|i| i.consume_key(Modifiers::COMMAND, Key::P)) {
+            self.toggle();
+        }
+        if !self.open {
+            return None;
+        }
+
+        let words: Vec<String> = self
+            .query
+            .split_whitespace()
+            .map(str::to_lowercase)
+            .collect();
+        let matching: Vec<(String, Command)> = Command::all()
+            .into_iter()
+            .filter(|(name, _)| {
+                let name = name.to_lowercase();
+                words.iter().all(|word| name.contains(word))
+            })
+            .collect();
+
+        // read before the text box, which would move its cursor with them
+        let (up, down, enter, escape) = ctx.input_mut(|i| {
+            (
+                i.consume_key(Modifiers::NONE, Key::ArrowUp),
+                i.consume_key(Modifiers::NONE, Key::ArrowDown),
+                i.consume_key(Modifiers::NONE, Key::Enter),
+                i.consume_key(Modifiers::NONE, Key::Escape),
+            )
+        });
+        if down {
+            self.selected += 1;
+        }
+        if up {
+            self.selected = self.selected.saturating_sub(1);
+        }
+        self.selected = self.selected.min(matching.len().saturating_sub(1));
+
+        let mut picked = match matching.get(self.selected) {
+            Some((_, command)) if enter => Some(command.clone()),
+            _ => None,
+        };
+
+        Window::new("Command Palette")
+            .title_bar(false)
+            .resizable(false)
+            .anchor(Align2::CENTER_TOP, [0.0, 40.0])
+            .fixed_size([360.0, 0.0])
+            .show(ctx, |ui| {
+                let edit = ui.add(
+                    TextEdit::singleline(&mut self.query)
+                        .hint_text("Type a command…")
+                        .desired_width(f32::INFINITY),
+                );
+                edit.request_focus();
+                if edit.changed() {
+                    self.selected = 0;
+                }
+
+                ui.separator();
+
+                ScrollArea::vertical().max_height(300.0).show(ui, |ui| {
+                    for (i, (name, command)) in matching.iter().enumerate() {
+                        let resp = ui.selectable_label(i == self.selected, name);
+                        if i == self.selected && (up || down) {
+                            resp.scroll_to_me(None);
+                        }
+                        if resp.clicked() {
+                            picked = Some(command.clone());
+                        }
+                    }
+
+                    if matching.is_empty() {
+                        ui.weak("No matching commands");
+                    }
+                });
+            });
+
+        if picked.is_some() || escape {
+            self.open = false;
+        }
+        picked
+    }
+}
+
+/// Shift+↑/↓ on a focused value take ten of the steps `DragValue` takes for
+/// ↑/↓, so values can be nudged coarsely without the mouse. Text boxes
+/// outside the pickers keep Shift+↑/↓ for selecting.
+pub fn coarse_nudge(ctx: &Context, fields: &Fields) {
+    if !fields.picker_focused(ctx) {
+        return;
+    }
+
+    ctx.input_mut(|i| {
+        let mut events = Vec::with_capacity(i.events.len());
+        for event in i.events.drain(..) {
+            match event {
+                Event::Key {
+                    key: key @ (Key::ArrowUp | Key::ArrowDown),
+                    physical_key,
+                    pressed: true,
+                    repeat,
+                    modifiers,
+                } if modifiers == Modifiers::SHIFT => {
+                    let step = Event::Key {
+                        key,
+                        physical_key,
+                        pressed: true,
+                        repeat,
+                        modifiers: Modifiers::NONE,
+                    };
+                    events.extend(std::iter::repeat_n(step, 10));
+                }
+                event => events.push(event),
+            }
+        }
+        i.events = events;
+    });
+}
@@ -1,7 +1,7 @@
 use eframe::{
     egui::{
-        collapsing_header::CollapsingState, style::Selection, Button, DragValue, Label, Layout,
-        Margin, Sense, Ui, Widget,
+        collapsing_header::CollapsingState, style::Selection, Button, ComboBox, DragValue, Label,
+        Layout, Margin, Sense, Ui, Widget,
     },
     emath::Align,
     epaint::{Color32, Rounding, Shadow, Stroke, Vec2},
@@ -186,6 +186,29 @@ pub fn shadow_picker<'a>(
             state.show_body_unindented(ui, |ui| {
                 ui.separator();
                 ui.horizontal(|ui| ui.add(color_row(&mut shadow.color)));
+                ui.columns(3, |cols| {
+                    cols[0].add(
+                        DragValue::new(&mut shadow.blur)
+                            .range(0.0..=100.0)
+                            .min_decimals(1)
+                            .speed(0.05)
+                            .prefix("Blur:"),
+                    );
+                    cols[1].add(
+                        DragValue::new(&mut shadow.offset.x)
+                            .range(-100.0..=100.0)
+                            .min_decimals(1)
+                            .speed(0.05)
+                            .prefix("X:"),
+                    );
+                    cols[2].add(
+                        DragValue::new(&mut shadow.offset.y)
+                            .range(-100.0..=100.0)
+                            .min_decimals(1)
+                            .speed(0.05)
+                            .prefix("Y:"),
+                    );
+                });
             });
 
             resp
@@ -487,6 +510,39 @@ pub fn margin_picker<'a>(
     }
 }
 
+pub fn enum_picker<'a, T: Copy + PartialEq>(
+    title: &'a str,
+    value: &'a mut T,
+    default: T,
+    options: &'a [T],
+    name: fn(&T) -> String,
+) -> impl Widget + 'a {
+    move |ui: &mut Ui| {
+        picker_frame(ui, |ui: &mut Ui| {
+            ui.horizontal(|ui| {
+                ui.label(title);
+                ui.with_layout(Layout::right_to_left(Align::Min), |ui| {
+                    if ui
+                        .add_enabled(*value != default, Button::new("⟲"))
+                        .clicked()
+                    {
+                        *value = default;
+                    }
+
+                    ComboBox::from_id_source(title)
+                        .selected_text(name(value))
+                        .show_ui(ui, |ui| {
+                            for option in options {
+                                ui.selectable_value(value, *option, name(option));
+                            }
+                        });
+                });
+            })
+            .response
+        })
+    }
+}
+
 fn color_row(color: &mut Color32) -> impl Widget + '_ {
     move |ui: &mut Ui| {
         ui.columns(4, |cols| {
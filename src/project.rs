@@ -0,0 +1,85 @@
+use std::sync::{mpsc::Sender, Arc, Mutex};
+
+use eframe::{
+    egui::{Context, Layout, Style, Ui},
+    emath::Align,
+};
+use egui_notify::Toast;
+use egui_themer::notes::{self, Notes};
+use rfd::AsyncFileDialog;
+
+/// Saves and opens project files, which keep field notes with the style.
+#[derive(Default)]
+pub struct ProjectMenu {
+    /// A project that's been opened but not applied yet.
+    opened: Arc<Mutex<Option<(Style, Notes)>>>,
+}
+
+impl ProjectMenu {
+    pub fn ui(&self, ui: &mut Ui, style: &Style, notes: &Notes, toasts_tx: Sender<Toast>) {
+        ui.horizontal(|ui| {
+            ui.label("Project")
+                .on_hover_text("The style with its field notes, which exports carry as comments.");
+            ui.with_layout(Layout::right_to_left(Align::Min), |ui| {
+                if ui.button("Open…").clicked() {
+                    self.open(ui.ctx(), toasts_tx.clone());
+                }
+                if ui.button("Save…").clicked() {
+                    match notes::save_project(style, notes) {
+                        Ok(project) => save(project),
+                        Err(e) => {
+                            toasts_tx
+                                .send(Toast::error(format!("Saving Project Failed: {e}")))
+                                .unwrap();
+                        }
+                    }
+                }
+                if !notes.is_empty() {
+                    ui.weak(format!("{} note(s)", notes.len()));
+                }
+            });
+        });
+    }
+
+    /// The style and notes of a project opened since the last call.
+    pub fn take_opened(&self) -> Option<(Style, Notes)> {
+        self.opened.lock().unwrap().take()
+    }
+
+    fn open(&self, ctx: &Context, toasts_tx: Sender<Toast>) {
+        let opened = self.opened.clone();
+        let ctx = ctx.clone();
+        let task = AsyncFileDialog::new()
+            .add_filter("Themer Project", &["json"])
+            .pick_file();
+
+        crate::execute_future(async move {
+            let Some(file) = task.await else {
+                return;
+            };
+
+            let toast = match notes::load_project(&file.read().await) {
+                Ok((migrated, notes)) => {
+                    *opened.lock().unwrap() = Some((migrated.style, notes));
+                    Toast::info("Project opened")
+                }
+                Err(e) => Toast::error(format!("Opening Project Failed: {e}")),
+            };
+            toasts_tx.send(toast).unwrap();
+            ctx.request_repaint();
+        });
+    }
+}
+
+fn save(project: String) {
+    let dialog = AsyncFileDialog::new()
+        .set_file_name("theme.themer.json")
+        .add_filter("Themer Project", &["json"])
+        .save_file();
+
+    crate::execute_future(async move {
+        if let Some(file) = dialog.await {
+            _ = file.write(project.as_bytes()).await;
+        }
+    });
+}
@@ -0,0 +1,48 @@
+//! Compact, URL-safe theme strings for share links.
+//!
+//! A share string is the style's JSON, deflated and base64url encoded. Links
+//! carry it in the fragment, as in `https://example.com/#theme=...`.
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use eframe::egui::Style;
+use miniz_oxide::{deflate::compress_to_vec, inflate::decompress_to_vec_with_limit};
+
+use crate::migrate::{self, Migrated};
+
+/// Where the web build is hosted, for links copied from the native app.
+pub const LIVE_URL: &str = "https://grantshandy.github.io/egui-themer/";
+
+/// Precedes the share string in a link's fragment.
+pub const FRAGMENT_KEY: &str = "theme=";
+
+/// Share strings can't inflate to more than this, so a malicious link can't
+/// exhaust memory.
+const MAX_JSON_LEN: usize = 1 << 20;
+
+/// Encode `style` as a share string.
+pub fn encode(style: &Style) -> Result<String, String> {
+    let json = serde_json::to_vec(style).map_err(|e| e.to_string())?;
+    Ok(URL_SAFE_NO_PAD.encode(compress_to_vec(&json, 9)))
+}
+
+/// A link to `base_url` that loads `style` on startup.
+pub fn link(base_url: &str, style: &Style) -> Result<String, String> {
+    let base_url = base_url.split('#').next().unwrap_or_default();
+    Ok(format!("{base_url}#{FRAGMENT_KEY}{}", encode(style)?))
+}
+
+/// Decode a share link, its fragment, or a bare share string, migrating the
+/// style inside like any other imported JSON.
+pub fn decode(text: &str) -> Result<Migrated, String> {
+    let text = text.trim();
+    let fragment = text.rsplit_once('#').map_or(text, |(_, fragment)| fragment);
+    let encoded = fragment.strip_prefix(FRAGMENT_KEY).unwrap_or(fragment);
+
+    let compressed = URL_SAFE_NO_PAD
+        .decode(encoded.trim_end_matches('='))
+        .map_err(|e| format!("not a theme link: {e}"))?;
+    let json = decompress_to_vec_with_limit(&compressed, MAX_JSON_LEN)
+        .map_err(|e| format!("not a theme link: {:?}", e.status))?;
+
+    migrate::import_style(&json)
+}
@@ -1,111 +1,286 @@
 use eframe::egui::{style::Spacing, Style, Ui};
 
 use crate::{
+    fields::Fields,
     pickers::{bool_picker, float_picker, margin_picker, vec2_picker},
-    section_title,
+    section_title_reset,
 };
 
 #[derive(Copy, Clone, PartialEq, Eq, Default)]
 pub struct SpacingMenu;
 
 impl SpacingMenu {
-    pub fn ui(&mut self, ui: &mut Ui, spacing: &mut Spacing) {
-        let default = Style::default().spacing;
+    pub fn ui(&mut self, ui: &mut Ui, fields: &mut Fields, spacing: &mut Spacing) {
+        let default = fields
+            .baseline()
+            .map_or_else(|| Style::default().spacing, |style| style.spacing.clone());
 
-        ui.add(section_title(
-            "Spacing",
-            Some(crate::egui_doc_link!("/style/struct.Spacing.html")),
-        ));
-        ui.add(vec2_picker(
-            "Item Spacing",
-            &mut spacing.item_spacing,
-            default.item_spacing,
-        ));
-        ui.add(margin_picker(
-            "Window Margin",
-            &mut spacing.window_margin,
-            default.window_margin,
-        ));
-        ui.add(vec2_picker(
-            "Button Padding",
-            &mut spacing.button_padding,
-            default.button_padding,
-        ));
-        ui.add(margin_picker(
-            "Menu Margin",
-            &mut spacing.menu_margin,
-            default.menu_margin,
-        ));
-        ui.add(float_picker("Indent", &mut spacing.indent, default.indent));
-        ui.add(vec2_picker(
-            "Interact Size",
-            &mut spacing.interact_size,
-            default.interact_size,
-        ));
-        ui.add(float_picker(
-            "Slider Width",
-            &mut spacing.slider_width,
-            default.slider_width,
-        ));
-        ui.add(float_picker(
-            "Combo Width",
-            &mut spacing.combo_width,
-            default.combo_width,
-        ));
-        ui.add(float_picker(
-            "Text Edit Width",
-            &mut spacing.text_edit_width,
-            default.text_edit_width,
-        ));
-        ui.add(float_picker(
-            "Icon Width",
-            &mut spacing.icon_width,
-            default.icon_width,
-        ));
-        ui.add(float_picker(
+        fields.section("Spacing");
+        if !fields.filtering()
+            && section_title_reset(
+                ui,
+                "Spacing",
+                Some(crate::egui_doc_link!("/style/struct.Spacing.html")),
+                *spacing != default,
+            )
+        {
+            *spacing = default.clone();
+            fields.bulk_edit();
+        }
+        fields.add(ui, "Item Spacing", "spacing.item_spacing", |title| {
+            vec2_picker(title, &mut spacing.item_spacing, default.item_spacing)
+        });
+        fields.add(ui, "Window Margin", "spacing.window_margin", |title| {
+            margin_picker(title, &mut spacing.window_margin, default.window_margin)
+        });
+        fields.add(ui, "Button Padding", "spacing.button_padding", |title| {
+            vec2_picker(title, &mut spacing.button_padding, default.button_padding)
+        });
+        fields.add(ui, "Menu Margin", "spacing.menu_margin", |title| {
+            margin_picker(title, &mut spacing.menu_margin, default.menu_margin)
+        });
+        fields.add(ui, "Indent", "spacing.indent", |title| {
+            float_picker(title, &mut spacing.indent, default.indent)
+        });
+        fields.add(ui, "Interact Size", "spacing.interact_size", |title| {
+            vec2_picker(title, &mut spacing.interact_size, default.interact_size)
+        });
+        fields.add(ui, "Slider Width", "spacing.slider_width", |title| {
+            float_picker(title, &mut spacing.slider_width, default.slider_width)
+        });
+        fields.add(ui, "Combo Width", "spacing.combo_width", |title| {
+            float_picker(title, &mut spacing.combo_width, default.combo_width)
+        });
+        fields.add(ui, "Text Edit Width", "spacing.text_edit_width", |title| {
+            float_picker(title, &mut spacing.text_edit_width, default.text_edit_width)
+        });
+        fields.add(ui, "Icon Width", "spacing.icon_width", |title| {
+            float_picker(title, &mut spacing.icon_width, default.icon_width)
+        });
+        fields.add(
+            ui,
             "Icon Width Inner",
-            &mut spacing.icon_width_inner,
-            default.icon_width_inner,
-        ));
-        ui.add(float_picker(
-            "Icon Spacing",
-            &mut spacing.icon_spacing,
-            default.icon_spacing,
-        ));
-        ui.add(float_picker(
-            "Tooltip Width",
-            &mut spacing.tooltip_width,
-            default.tooltip_width,
-        ));
-        ui.add(bool_picker(
+            "spacing.icon_width_inner",
+            |title| {
+                float_picker(
+                    title,
+                    &mut spacing.icon_width_inner,
+                    default.icon_width_inner,
+                )
+            },
+        );
+        fields.add(
+            ui,
+            "Slider Rail Height",
+            "spacing.slider_rail_height",
+            |title| {
+                float_picker(
+                    title,
+                    &mut spacing.slider_rail_height,
+                    default.slider_rail_height,
+                )
+            },
+        );
+        fields.add(ui, "Icon Spacing", "spacing.icon_spacing", |title| {
+            float_picker(title, &mut spacing.icon_spacing, default.icon_spacing)
+        });
+        fields.add(ui, "Tooltip Width", "spacing.tooltip_width", |title| {
+            float_picker(title, &mut spacing.tooltip_width, default.tooltip_width)
+        });
+        fields.add(
+            ui,
             "Indent Ends With Horizontal Line",
-            &mut spacing.indent_ends_with_horizontal_line,
-            default.indent_ends_with_horizontal_line,
-        ));
-        ui.add(float_picker(
-            "Combo Height",
-            &mut spacing.combo_height,
-            default.combo_height,
-        ));
-        ui.add(float_picker(
+            "spacing.indent_ends_with_horizontal_line",
+            |title| {
+                bool_picker(
+                    title,
+                    &mut spacing.indent_ends_with_horizontal_line,
+                    default.indent_ends_with_horizontal_line,
+                )
+            },
+        );
+        fields.add(ui, "Menu Width", "spacing.menu_width", |title| {
+            float_picker(title, &mut spacing.menu_width, default.menu_width)
+        });
+        fields.add(ui, "Menu Spacing", "spacing.menu_spacing", |title| {
+            float_picker(title, &mut spacing.menu_spacing, default.menu_spacing)
+        });
+        fields.add(
+            ui,
+            "Default Area Size",
+            "spacing.default_area_size",
+            |title| {
+                vec2_picker(
+                    title,
+                    &mut spacing.default_area_size,
+                    default.default_area_size,
+                )
+            },
+        );
+        fields.add(ui, "Combo Height", "spacing.combo_height", |title| {
+            float_picker(title, &mut spacing.combo_height, default.combo_height)
+        });
+        fields.add(
+            ui,
             "Scroll Bar Width",
-            &mut spacing.scroll.bar_width,
-            default.scroll.bar_width,
-        ));
-        ui.add(float_picker(
+            "spacing.scroll.bar_width",
+            |title| {
+                float_picker(
+                    title,
+                    &mut spacing.scroll.bar_width,
+                    default.scroll.bar_width,
+                )
+            },
+        );
+        fields.add(
+            ui,
             "Scroll Bar Handle Min Length",
-            &mut spacing.scroll.handle_min_length,
-            default.scroll.handle_min_length,
-        ));
-        ui.add(float_picker(
+            "spacing.scroll.handle_min_length",
+            |title| {
+                float_picker(
+                    title,
+                    &mut spacing.scroll.handle_min_length,
+                    default.scroll.handle_min_length,
+                )
+            },
+        );
+        fields.add(
+            ui,
             "Scroll Bar Inner Margin",
-            &mut spacing.scroll.bar_inner_margin,
-            default.scroll.bar_inner_margin,
-        ));
-        ui.add(float_picker(
+            "spacing.scroll.bar_inner_margin",
+            |title| {
+                float_picker(
+                    title,
+                    &mut spacing.scroll.bar_inner_margin,
+                    default.scroll.bar_inner_margin,
+                )
+            },
+        );
+        fields.add(
+            ui,
             "Scroll Bar Outer Margin",
-            &mut spacing.scroll.bar_outer_margin,
-            default.scroll.bar_outer_margin,
-        ));
+            "spacing.scroll.bar_outer_margin",
+            |title| {
+                float_picker(
+                    title,
+                    &mut spacing.scroll.bar_outer_margin,
+                    default.scroll.bar_outer_margin,
+                )
+            },
+        );
+        fields.add(
+            ui,
+            "Scroll Bar Floating",
+            "spacing.scroll.floating",
+            |title| bool_picker(title, &mut spacing.scroll.floating, default.scroll.floating),
+        );
+        fields.add(
+            ui,
+            "Scroll Bar Floating Width",
+            "spacing.scroll.floating_width",
+            |title| {
+                float_picker(
+                    title,
+                    &mut spacing.scroll.floating_width,
+                    default.scroll.floating_width,
+                )
+            },
+        );
+        fields.add(
+            ui,
+            "Scroll Bar Floating Allocated Width",
+            "spacing.scroll.floating_allocated_width",
+            |title| {
+                float_picker(
+                    title,
+                    &mut spacing.scroll.floating_allocated_width,
+                    default.scroll.floating_allocated_width,
+                )
+            },
+        );
+        fields.add(
+            ui,
+            "Scroll Bar Foreground Color",
+            "spacing.scroll.foreground_color",
+            |title| {
+                bool_picker(
+                    title,
+                    &mut spacing.scroll.foreground_color,
+                    default.scroll.foreground_color,
+                )
+            },
+        );
+        fields.add(
+            ui,
+            "Scroll Bar Dormant Background Opacity",
+            "spacing.scroll.dormant_background_opacity",
+            |title| {
+                float_picker(
+                    title,
+                    &mut spacing.scroll.dormant_background_opacity,
+                    default.scroll.dormant_background_opacity,
+                )
+            },
+        );
+        fields.add(
+            ui,
+            "Scroll Bar Dormant Handle Opacity",
+            "spacing.scroll.dormant_handle_opacity",
+            |title| {
+                float_picker(
+                    title,
+                    &mut spacing.scroll.dormant_handle_opacity,
+                    default.scroll.dormant_handle_opacity,
+                )
+            },
+        );
+        fields.add(
+            ui,
+            "Scroll Bar Active Background Opacity",
+            "spacing.scroll.active_background_opacity",
+            |title| {
+                float_picker(
+                    title,
+                    &mut spacing.scroll.active_background_opacity,
+                    default.scroll.active_background_opacity,
+                )
+            },
+        );
+        fields.add(
+            ui,
+            "Scroll Bar Active Handle Opacity",
+            "spacing.scroll.active_handle_opacity",
+            |title| {
+                float_picker(
+                    title,
+                    &mut spacing.scroll.active_handle_opacity,
+                    default.scroll.active_handle_opacity,
+                )
+            },
+        );
+        fields.add(
+            ui,
+            "Scroll Bar Interact Background Opacity",
+            "spacing.scroll.interact_background_opacity",
+            |title| {
+                float_picker(
+                    title,
+                    &mut spacing.scroll.interact_background_opacity,
+                    default.scroll.interact_background_opacity,
+                )
+            },
+        );
+        fields.add(
+            ui,
+            "Scroll Bar Interact Handle Opacity",
+            "spacing.scroll.interact_handle_opacity",
+            |title| {
+                float_picker(
+                    title,
+                    &mut spacing.scroll.interact_handle_opacity,
+                    default.scroll.interact_handle_opacity,
+                )
+            },
+        );
     }
 }
@@ -0,0 +1,164 @@
+//! The themeable values of a `Style`, named by their field paths, for
+//! exporters that target formats other than egui's own.
+
+use eframe::{
+    egui::{Margin, Rounding, Stroke, Style, Vec2},
+    epaint::{Color32, Shadow},
+};
+
+/// A typed, mutable view of one value in a `Style`.
+pub enum Token<'a> {
+    Color(&'a mut Color32),
+    /// A length in points.
+    Dimension(&'a mut f32),
+    Vec2(&'a mut Vec2),
+    Margin(&'a mut Margin),
+    Rounding(&'a mut Rounding),
+    Stroke(&'a mut Stroke),
+    Shadow(&'a mut Shadow),
+}
+
+/// Call `f` with the dotted field path and value of every token in `style`,
+/// always in the same order.
+pub fn visit(style: &mut Style, f: &mut dyn FnMut(&str, Token)) {
+    let Style {
+        spacing, visuals, ..
+    } = style;
+
+    for (field, token) in [
+        ("item_spacing", Token::Vec2(&mut spacing.item_spacing)),
+        ("window_margin", Token::Margin(&mut spacing.window_margin)),
+        ("menu_margin", Token::Margin(&mut spacing.menu_margin)),
+        ("button_padding", Token::Vec2(&mut spacing.button_padding)),
+        ("indent", Token::Dimension(&mut spacing.indent)),
+        ("interact_size", Token::Vec2(&mut spacing.interact_size)),
+        ("slider_width", Token::Dimension(&mut spacing.slider_width)),
+        (
+            "slider_rail_height",
+            Token::Dimension(&mut spacing.slider_rail_height),
+        ),
+        ("combo_width", Token::Dimension(&mut spacing.combo_width)),
+        (
+            "text_edit_width",
+            Token::Dimension(&mut spacing.text_edit_width),
+        ),
+        ("icon_width", Token::Dimension(&mut spacing.icon_width)),
+        (
+            "icon_width_inner",
+            Token::Dimension(&mut spacing.icon_width_inner),
+        ),
+        ("icon_spacing", Token::Dimension(&mut spacing.icon_spacing)),
+        (
+            "default_area_size",
+            Token::Vec2(&mut spacing.default_area_size),
+        ),
+        (
+            "tooltip_width",
+            Token::Dimension(&mut spacing.tooltip_width),
+        ),
+        ("menu_width", Token::Dimension(&mut spacing.menu_width)),
+        ("menu_spacing", Token::Dimension(&mut spacing.menu_spacing)),
+        ("combo_height", Token::Dimension(&mut spacing.combo_height)),
+        (
+            "scroll.bar_width",
+            Token::Dimension(&mut spacing.scroll.bar_width),
+        ),
+        (
+            "scroll.handle_min_length",
+            Token::Dimension(&mut spacing.scroll.handle_min_length),
+        ),
+        (
+            "scroll.bar_inner_margin",
+            Token::Dimension(&mut spacing.scroll.bar_inner_margin),
+        ),
+        (
+            "scroll.bar_outer_margin",
+            Token::Dimension(&mut spacing.scroll.bar_outer_margin),
+        ),
+        (
+            "scroll.floating_width",
+            Token::Dimension(&mut spacing.scroll.floating_width),
+        ),
+        (
+            "scroll.floating_allocated_width",
+            Token::Dimension(&mut spacing.scroll.floating_allocated_width),
+        ),
+    ] {
+        f(&format!("spacing.{field}"), token);
+    }
+
+    for (state, widget) in [
+        ("noninteractive", &mut visuals.widgets.noninteractive),
+        ("inactive", &mut visuals.widgets.inactive),
+        ("hovered", &mut visuals.widgets.hovered),
+        ("active", &mut visuals.widgets.active),
+        ("open", &mut visuals.widgets.open),
+    ] {
+        let prefix = format!("visuals.widgets.{state}");
+        for (field, token) in [
+            ("bg_fill", Token::Color(&mut widget.bg_fill)),
+            ("weak_bg_fill", Token::Color(&mut widget.weak_bg_fill)),
+            ("bg_stroke", Token::Stroke(&mut widget.bg_stroke)),
+            ("rounding", Token::Rounding(&mut widget.rounding)),
+            ("fg_stroke", Token::Stroke(&mut widget.fg_stroke)),
+            ("expansion", Token::Dimension(&mut widget.expansion)),
+        ] {
+            f(&format!("{prefix}.{field}"), token);
+        }
+    }
+
+    for (field, token) in [
+        (
+            "selection.bg_fill",
+            Token::Color(&mut visuals.selection.bg_fill),
+        ),
+        (
+            "selection.stroke",
+            Token::Stroke(&mut visuals.selection.stroke),
+        ),
+        (
+            "hyperlink_color",
+            Token::Color(&mut visuals.hyperlink_color),
+        ),
+        ("faint_bg_color", Token::Color(&mut visuals.faint_bg_color)),
+        (
+            "extreme_bg_color",
+            Token::Color(&mut visuals.extreme_bg_color),
+        ),
+        ("code_bg_color", Token::Color(&mut visuals.code_bg_color)),
+        ("warn_fg_color", Token::Color(&mut visuals.warn_fg_color)),
+        ("error_fg_color", Token::Color(&mut visuals.error_fg_color)),
+        (
+            "window_rounding",
+            Token::Rounding(&mut visuals.window_rounding),
+        ),
+        ("window_shadow", Token::Shadow(&mut visuals.window_shadow)),
+        ("window_fill", Token::Color(&mut visuals.window_fill)),
+        ("window_stroke", Token::Stroke(&mut visuals.window_stroke)),
+        ("menu_rounding", Token::Rounding(&mut visuals.menu_rounding)),
+        ("panel_fill", Token::Color(&mut visuals.panel_fill)),
+        ("popup_shadow", Token::Shadow(&mut visuals.popup_shadow)),
+        (
+            "resize_corner_size",
+            Token::Dimension(&mut visuals.resize_corner_size),
+        ),
+        (
+            "text_cursor.stroke",
+            Token::Stroke(&mut visuals.text_cursor.stroke),
+        ),
+        (
+            "clip_rect_margin",
+            Token::Dimension(&mut visuals.clip_rect_margin),
+        ),
+    ] {
+        f(&format!("visuals.{field}"), token);
+    }
+}
+
+/// `Color32` as an unpremultiplied `#rrggbb`, or `#rrggbbaa` when translucent.
+pub fn hex_color(color: Color32) -> String {
+    match color.to_srgba_unmultiplied() {
+        [r, g, b, 255] => format!("#{r:02x}{g:02x}{b:02x}"),
+        [r, g, b, a] => format!("#{r:02x}{g:02x}{b:02x}{a:02x}"),
+    }
+}
@@ -1,15 +1,20 @@
 use eframe::{
-    egui::{style::WidgetVisuals, Button, Label, Layout, Response, Sense, Ui, Visuals, Widget},
+    egui::{
+        style::{HandleShape, NumericColorSpace, WidgetVisuals, Widgets},
+        Button, CollapsingHeader, Color32, ComboBox, CursorIcon, Direction, DragValue, Grid, Label,
+        Layout, Response, Rounding, Sense, Ui, Visuals, Widget,
+    },
     emath::Align,
 };
 
 use crate::{
+    fields::Fields,
     picker_frame,
     pickers::{
-        bool_picker, color_picker, color_picker_optional, float_picker, rounding_picker,
-        selection_picker, shadow_picker, stroke_picker,
+        bool_picker, color_picker, color_picker_optional, enum_picker, float_picker,
+        rounding_picker, selection_picker, shadow_picker, stroke_picker,
     },
-    section_title,
+    section_title_reset,
 };
 
 pub struct VisualsMenu {
@@ -23,6 +28,10 @@ pub struct VisualsMenu {
     hovered_rounding: (bool, f32),
     active_rounding: (bool, f32),
     open_rounding: (bool, f32),
+    /// A widget state copied to be pasted over another.
+    copied_widget: Option<WidgetVisuals>,
+    /// How Hovered, Active and Open are derived from Inactive, in that order.
+    derivations: [Derivation; 3],
 }
 
 impl Default for VisualsMenu {
@@ -38,238 +47,662 @@ impl Default for VisualsMenu {
             hovered_rounding: (true, 3.0),
             active_rounding: (true, 2.0),
             open_rounding: (true, 2.0),
+            copied_widget: None,
+            derivations: [
+                Derivation {
+                    lighten: 0.1,
+                    expand: 1.0,
+                },
+                Derivation {
+                    lighten: 0.25,
+                    expand: 1.0,
+                },
+                Derivation {
+                    lighten: -0.1,
+                    expand: 0.0,
+                },
+            ],
         }
     }
 }
 
 impl VisualsMenu {
-    pub fn ui(&mut self, ui: &mut Ui, visuals: &mut Visuals) {
-        self.visuals_default = if visuals.dark_mode {
-            Visuals::dark()
-        } else {
-            Visuals::light()
+    pub fn ui(&mut self, ui: &mut Ui, fields: &mut Fields, visuals: &mut Visuals) {
+        self.visuals_default = match fields.baseline() {
+            Some(style) => style.visuals.clone(),
+            None if visuals.dark_mode => Visuals::dark(),
+            None => Visuals::light(),
         };
 
-        ui.add(section_title(
-            "Visuals",
+        if fields.filtering() {
+            for tab in TabState::ALL {
+                self.tab(ui, fields, visuals, tab);
+            }
+            return;
+        }
+
+        let mut reset = visuals.clone();
+        self.reset_tab(&mut reset);
+        if section_title_reset(
+            ui,
+            &format!("Visuals › {}", self.tab_name()),
             Some(crate::egui_doc_link!("/style/struct.Visuals.html")),
-        ));
+            reset != *visuals,
+        ) {
+            *visuals = reset;
+            self.sync_roundings(visuals);
+            fields.bulk_edit();
+        }
 
         self.tab_state.show(ui);
+        self.tab(ui, fields, visuals, self.tab_state);
+    }
+
+    /// Match the rounding pickers' Uniform options to `visuals` after it's been
+    /// replaced, or they'd put their uniform values back.
+    pub fn sync_roundings(&mut self, visuals: &Visuals) {
+        self.menu_rounding = uniform(visuals.menu_rounding);
+        self.window_rounding = uniform(visuals.window_rounding);
+        for state in WidgetTabState::ALL {
+            *self.rounding_uniform(state) = uniform(state.widget(&visuals.widgets).rounding);
+        }
+    }
 
+    /// The open tab, and widget state if it's the Widgets tab.
+    fn tab_name(&self) -> String {
         match self.tab_state {
-            TabState::Misc => self.misc(ui, visuals),
-            TabState::Colors => self.colors(ui, visuals),
-            TabState::Window => self.window(ui, visuals),
-            TabState::Widgets => self.widgets(ui, visuals),
+            TabState::Widgets => format!(
+                "{} › {}",
+                self.tab_state.name(),
+                self.widget_tab_state.name()
+            ),
+            tab => tab.name().to_owned(),
         }
     }
 
-    fn misc(&mut self, ui: &mut Ui, visuals: &mut Visuals) {
-        ui.add(dark_light_mode_picker(&mut visuals.dark_mode));
-        ui.add(color_picker_optional(
+    /// Set the fields of the open tab in `visuals` back to the baseline.
+    fn reset_tab(&self, visuals: &mut Visuals) {
+        let default = &self.visuals_default;
+
+        match self.tab_state {
+            TabState::Misc => {
+                // everything but the other tabs' fields
+                let edited = std::mem::replace(visuals, default.clone());
+                visuals.widgets = edited.widgets;
+                visuals.window_rounding = edited.window_rounding;
+                visuals.window_shadow = edited.window_shadow;
+                visuals.window_fill = edited.window_fill;
+                visuals.window_stroke = edited.window_stroke;
+                visuals.window_highlight_topmost = edited.window_highlight_topmost;
+                visuals.hyperlink_color = edited.hyperlink_color;
+                visuals.faint_bg_color = edited.faint_bg_color;
+                visuals.extreme_bg_color = edited.extreme_bg_color;
+                visuals.code_bg_color = edited.code_bg_color;
+                visuals.warn_fg_color = edited.warn_fg_color;
+                visuals.error_fg_color = edited.error_fg_color;
+            }
+            TabState::Window => {
+                visuals.window_rounding = default.window_rounding;
+                visuals.window_shadow = default.window_shadow;
+                visuals.window_fill = default.window_fill;
+                visuals.window_stroke = default.window_stroke;
+                visuals.window_highlight_topmost = default.window_highlight_topmost;
+            }
+            TabState::Colors => {
+                visuals.hyperlink_color = default.hyperlink_color;
+                visuals.faint_bg_color = default.faint_bg_color;
+                visuals.extreme_bg_color = default.extreme_bg_color;
+                visuals.code_bg_color = default.code_bg_color;
+                visuals.warn_fg_color = default.warn_fg_color;
+                visuals.error_fg_color = default.error_fg_color;
+            }
+            TabState::Widgets => {
+                let state = self.widget_tab_state;
+                *state.widget_mut(&mut visuals.widgets) = *state.widget(&default.widgets);
+            }
+        }
+    }
+
+    fn rounding_uniform(&mut self, state: WidgetTabState) -> &mut (bool, f32) {
+        match state {
+            WidgetTabState::NonInteractive => &mut self.noninteractive_rounding,
+            WidgetTabState::Inactive => &mut self.inactive_rounding,
+            WidgetTabState::Hovered => &mut self.hovered_rounding,
+            WidgetTabState::Active => &mut self.active_rounding,
+            WidgetTabState::Open => &mut self.open_rounding,
+        }
+    }
+
+    /// Every tab, with the Widgets tab split by state, e.g. "Widgets › Hovered".
+    pub fn tab_names() -> Vec<String> {
+        TabState::ALL
+            .into_iter()
+            .flat_map(|tab| match tab {
+                TabState::Widgets => WidgetTabState::ALL
+                    .into_iter()
+                    .map(|state| format!("{} › {}", tab.name(), state.name()))
+                    .collect(),
+                tab => vec![tab.name().to_owned()],
+            })
+            .collect()
+    }
+
+    /// Switch to a tab named by [`Self::tab_names`].
+    pub fn show_tab(&mut self, name: &str) {
+        let (tab, state) = name.split_once(" › ").unwrap_or((name, ""));
+
+        if let Some(tab) = TabState::ALL.into_iter().find(|t| t.name() == tab) {
+            self.tab_state = tab;
+        }
+        if let Some(state) = WidgetTabState::ALL.into_iter().find(|s| s.name() == state) {
+            self.widget_tab_state = state;
+        }
+    }
+
+    /// Switch to the tab holding the field at `path`.
+    pub fn inspect(&mut self, path: &str) {
+        let mut segments = path.split('.').skip_while(|segment| *segment == "visuals");
+
+        self.tab_state = match segments.next() {
+            Some("widgets") => {
+                if let Some(state) = segments.next().and_then(WidgetTabState::from_field) {
+                    self.widget_tab_state = state;
+                }
+                TabState::Widgets
+            }
+            Some(
+                "window_rounding"
+                | "window_shadow"
+                | "window_fill"
+                | "window_stroke"
+                | "window_highlight_topmost",
+            ) => TabState::Window,
+            Some(
+                "hyperlink_color" | "faint_bg_color" | "extreme_bg_color" | "code_bg_color"
+                | "warn_fg_color" | "error_fg_color",
+            ) => TabState::Colors,
+            _ => TabState::Misc,
+        };
+    }
+
+    fn tab(&mut self, ui: &mut Ui, fields: &mut Fields, visuals: &mut Visuals, tab: TabState) {
+        fields.section(format!("Visuals › {}", tab.name()));
+
+        match tab {
+            TabState::Misc => self.misc(ui, fields, visuals),
+            TabState::Colors => self.colors(ui, fields, visuals),
+            TabState::Window => self.window(ui, fields, visuals),
+            TabState::Widgets if fields.filtering() => {
+                for state in WidgetTabState::ALL {
+                    fields.section(format!("Visuals › Widgets › {}", state.name()));
+                    self.widgets(ui, fields, visuals, state);
+                }
+            }
+            TabState::Widgets => {
+                self.widget_tab_state.show(ui);
+                self.widget_state_actions(ui, fields, visuals);
+                self.widgets(ui, fields, visuals, self.widget_tab_state);
+            }
+        }
+    }
+
+    /// Copying the open widget state to paste over another, and deriving the
+    /// interactive states from Inactive.
+    fn widget_state_actions(&mut self, ui: &mut Ui, fields: &mut Fields, visuals: &mut Visuals) {
+        let state = self.widget_tab_state;
+
+        ui.horizontal(|ui| {
+            ui.label(format!("{} State", state.name()));
+            ui.with_layout(Layout::right_to_left(Align::Min), |ui| {
+                if let Some(copied) = self.copied_widget {
+                    if ui
+                        .button("Paste")
+                        .on_hover_text(format!("Replace {} with the copied state.", state.name()))
+                        .clicked()
+                    {
+                        *state.widget_mut(&mut visuals.widgets) = copied;
+                        *self.rounding_uniform(state) = uniform(copied.rounding);
+                        fields.bulk_edit();
+                    }
+                }
+                if ui.button("Copy").clicked() {
+                    self.copied_widget = Some(*state.widget(&visuals.widgets));
+                }
+            });
+        });
+
+        CollapsingHeader::new("Derive From Inactive")
+            .id_source("derive_widget_states")
+            .show(ui, |ui| {
+                Grid::new("derive_widget_states_grid")
+                    .num_columns(3)
+                    .show(ui, |ui| {
+                        ui.label("");
+                        ui.strong("Lighten").on_hover_text(
+                            "How far colors move toward white, or black if negative.",
+                        );
+                        ui.strong("Expand");
+                        ui.end_row();
+
+                        for (state, derivation) in WidgetTabState::DERIVED
+                            .into_iter()
+                            .zip(&mut self.derivations)
+                        {
+                            ui.label(state.name());
+                            ui.add(
+                                DragValue::new(&mut derivation.lighten)
+                                    .speed(0.01)
+                                    .range(-1.0..=1.0),
+                            );
+                            ui.add(DragValue::new(&mut derivation.expand).speed(0.1));
+                            ui.end_row();
+                        }
+                    });
+
+                ui.allocate_ui_with_layout(
+                    [ui.available_width(), 0.0].into(),
+                    Layout::centered_and_justified(Direction::TopDown),
+                    |ui| {
+                        if ui
+                            .button("Derive")
+                            .on_hover_text("Replace Hovered, Active and Open.")
+                            .clicked()
+                        {
+                            let inactive = visuals.widgets.inactive;
+                            for (state, derivation) in
+                                WidgetTabState::DERIVED.into_iter().zip(self.derivations)
+                            {
+                                *state.widget_mut(&mut visuals.widgets) =
+                                    derivation.apply(inactive);
+                                *self.rounding_uniform(state) = uniform(inactive.rounding);
+                            }
+                            fields.bulk_edit();
+                        }
+                    },
+                );
+            });
+    }
+
+    fn misc(&mut self, ui: &mut Ui, fields: &mut Fields, visuals: &mut Visuals) {
+        fields.add(ui, "Dark Mode", "visuals.dark_mode", |title| {
+            dark_light_mode_picker(title, &mut visuals.dark_mode)
+        });
+        fields.add(
+            ui,
             "Override Text Color",
-            &mut visuals.override_text_color,
-            self.visuals_default.override_text_color,
-        ));
-        ui.add(selection_picker(
-            "Selection",
-            &mut visuals.selection,
-            self.visuals_default.selection,
-        ));
-        ui.add(rounding_picker(
-            "Menu Rounding",
-            &mut self.menu_rounding,
-            &mut visuals.menu_rounding,
-            (
-                self.visuals_default.menu_rounding,
-                Self::default().menu_rounding,
-            ),
-        ));
-        ui.add(color_picker(
-            "Panel Fill",
-            &mut visuals.panel_fill,
-            self.visuals_default.panel_fill,
-        ));
-        ui.add(shadow_picker(
-            "Popup Shadow",
-            &mut visuals.popup_shadow,
-            self.visuals_default.popup_shadow,
-        ));
-        ui.add(float_picker(
+            "visuals.override_text_color",
+            |title| {
+                color_picker_optional(
+                    title,
+                    &mut visuals.override_text_color,
+                    self.visuals_default.override_text_color,
+                )
+            },
+        );
+        fields.add(ui, "Selection", "visuals.selection", |title| {
+            selection_picker(
+                title,
+                &mut visuals.selection,
+                self.visuals_default.selection,
+            )
+        });
+        fields.add(ui, "Menu Rounding", "visuals.menu_rounding", |title| {
+            rounding_picker(
+                title,
+                &mut self.menu_rounding,
+                &mut visuals.menu_rounding,
+                (
+                    self.visuals_default.menu_rounding,
+                    uniform(self.visuals_default.menu_rounding),
+                ),
+            )
+        });
+        fields.add(ui, "Panel Fill", "visuals.panel_fill", |title| {
+            color_picker(
+                title,
+                &mut visuals.panel_fill,
+                self.visuals_default.panel_fill,
+            )
+        });
+        fields.add(ui, "Popup Shadow", "visuals.popup_shadow", |title| {
+            shadow_picker(
+                title,
+                &mut visuals.popup_shadow,
+                self.visuals_default.popup_shadow,
+            )
+        });
+        fields.add(
+            ui,
             "Resize Corner Size",
-            &mut visuals.resize_corner_size,
-            self.visuals_default.resize_corner_size,
-        ));
-        ui.add(float_picker(
+            "visuals.resize_corner_size",
+            |title| {
+                float_picker(
+                    title,
+                    &mut visuals.resize_corner_size,
+                    self.visuals_default.resize_corner_size,
+                )
+            },
+        );
+        fields.add(
+            ui,
             "Text Cursor Width",
-            &mut visuals.text_cursor.stroke.width,
-            self.visuals_default.text_cursor.stroke.width,
-        ));
-        ui.add(bool_picker(
+            "visuals.text_cursor.stroke.width",
+            |title| {
+                float_picker(
+                    title,
+                    &mut visuals.text_cursor.stroke.width,
+                    self.visuals_default.text_cursor.stroke.width,
+                )
+            },
+        );
+        fields.add(
+            ui,
             "Text Cursor Preview",
-            &mut visuals.text_cursor.preview,
-            self.visuals_default.text_cursor.preview,
-        ));
-        ui.add(float_picker(
+            "visuals.text_cursor.preview",
+            |title| {
+                bool_picker(
+                    title,
+                    &mut visuals.text_cursor.preview,
+                    self.visuals_default.text_cursor.preview,
+                )
+            },
+        );
+        fields.add(
+            ui,
+            "Text Cursor Blink",
+            "visuals.text_cursor.blink",
+            |title| {
+                bool_picker(
+                    title,
+                    &mut visuals.text_cursor.blink,
+                    self.visuals_default.text_cursor.blink,
+                )
+            },
+        );
+        fields.add(
+            ui,
+            "Text Cursor On Duration",
+            "visuals.text_cursor.on_duration",
+            |title| {
+                float_picker(
+                    title,
+                    &mut visuals.text_cursor.on_duration,
+                    self.visuals_default.text_cursor.on_duration,
+                )
+            },
+        );
+        fields.add(
+            ui,
+            "Text Cursor Off Duration",
+            "visuals.text_cursor.off_duration",
+            |title| {
+                float_picker(
+                    title,
+                    &mut visuals.text_cursor.off_duration,
+                    self.visuals_default.text_cursor.off_duration,
+                )
+            },
+        );
+        fields.add(
+            ui,
             "Clip Rect Margin",
-            &mut visuals.clip_rect_margin,
-            self.visuals_default.clip_rect_margin,
-        ));
-        ui.add(bool_picker(
-            "Button Frame",
-            &mut visuals.button_frame,
-            self.visuals_default.button_frame,
-        ));
-        ui.add(bool_picker(
+            "visuals.clip_rect_margin",
+            |title| {
+                float_picker(
+                    title,
+                    &mut visuals.clip_rect_margin,
+                    self.visuals_default.clip_rect_margin,
+                )
+            },
+        );
+        fields.add(ui, "Button Frame", "visuals.button_frame", |title| {
+            bool_picker(
+                title,
+                &mut visuals.button_frame,
+                self.visuals_default.button_frame,
+            )
+        });
+        fields.add(
+            ui,
             "Collapsing Header Frame",
-            &mut visuals.collapsing_header_frame,
-            self.visuals_default.collapsing_header_frame,
-        ));
-        ui.add(bool_picker(
+            "visuals.collapsing_header_frame",
+            |title| {
+                bool_picker(
+                    title,
+                    &mut visuals.collapsing_header_frame,
+                    self.visuals_default.collapsing_header_frame,
+                )
+            },
+        );
+        fields.add(
+            ui,
             "Indent Left Vline",
-            &mut visuals.indent_has_left_vline,
-            self.visuals_default.indent_has_left_vline,
-        ));
-        ui.add(bool_picker(
-            "Striped",
-            &mut visuals.striped,
-            self.visuals_default.striped,
-        ));
-        ui.add(bool_picker(
+            "visuals.indent_has_left_vline",
+            |title| {
+                bool_picker(
+                    title,
+                    &mut visuals.indent_has_left_vline,
+                    self.visuals_default.indent_has_left_vline,
+                )
+            },
+        );
+        fields.add(ui, "Striped", "visuals.striped", |title| {
+            bool_picker(title, &mut visuals.striped, self.visuals_default.striped)
+        });
+        fields.add(
+            ui,
             "Slider Trailing Fill",
-            &mut visuals.slider_trailing_fill,
-            self.visuals_default.slider_trailing_fill,
-        ));
+            "visuals.slider_trailing_fill",
+            |title| {
+                bool_picker(
+                    title,
+                    &mut visuals.slider_trailing_fill,
+                    self.visuals_default.slider_trailing_fill,
+                )
+            },
+        );
+        fields.add(ui, "Handle Shape", "visuals.handle_shape", |title| {
+            handle_shape_picker(
+                title,
+                &mut visuals.handle_shape,
+                self.visuals_default.handle_shape,
+            )
+        });
+
+        let cursors: Vec<Option<CursorIcon>> = std::iter::once(None)
+            .chain(CursorIcon::ALL.into_iter().map(Some))
+            .collect();
+        fields.add(ui, "Interact Cursor", "visuals.interact_cursor", |title| {
+            enum_picker(
+                title,
+                &mut visuals.interact_cursor,
+                self.visuals_default.interact_cursor,
+                &cursors,
+                |cursor| cursor.map_or("None".to_owned(), |cursor| format!("{cursor:?}")),
+            )
+        });
+        fields.add(
+            ui,
+            "Image Loading Spinners",
+            "visuals.image_loading_spinners",
+            |title| {
+                bool_picker(
+                    title,
+                    &mut visuals.image_loading_spinners,
+                    self.visuals_default.image_loading_spinners,
+                )
+            },
+        );
+        fields.add(
+            ui,
+            "Numeric Color Space",
+            "visuals.numeric_color_space",
+            |title| {
+                enum_picker(
+                    title,
+                    &mut visuals.numeric_color_space,
+                    self.visuals_default.numeric_color_space,
+                    &[NumericColorSpace::GammaByte, NumericColorSpace::Linear],
+                    |space| format!("{space:?}"),
+                )
+            },
+        );
     }
 
-    fn window(&mut self, ui: &mut Ui, visuals: &mut Visuals) {
-        ui.add(rounding_picker(
-            "Rounding",
-            &mut self.window_rounding,
-            &mut visuals.window_rounding,
-            (
-                self.visuals_default.window_rounding,
-                Self::default().window_rounding,
-            ),
-        ));
-        ui.add(shadow_picker(
-            "Shadow",
-            &mut visuals.window_shadow,
-            self.visuals_default.window_shadow,
-        ));
-        ui.add(color_picker(
-            "Fill",
-            &mut visuals.window_fill,
-            self.visuals_default.window_fill,
-        ));
-        ui.add(stroke_picker(
-            "Stroke",
-            &mut visuals.window_stroke,
-            self.visuals_default.window_stroke,
-        ));
+    fn window(&mut self, ui: &mut Ui, fields: &mut Fields, visuals: &mut Visuals) {
+        fields.add(ui, "Rounding", "visuals.window_rounding", |title| {
+            rounding_picker(
+                title,
+                &mut self.window_rounding,
+                &mut visuals.window_rounding,
+                (
+                    self.visuals_default.window_rounding,
+                    uniform(self.visuals_default.window_rounding),
+                ),
+            )
+        });
+        fields.add(ui, "Shadow", "visuals.window_shadow", |title| {
+            shadow_picker(
+                title,
+                &mut visuals.window_shadow,
+                self.visuals_default.window_shadow,
+            )
+        });
+        fields.add(ui, "Fill", "visuals.window_fill", |title| {
+            color_picker(
+                title,
+                &mut visuals.window_fill,
+                self.visuals_default.window_fill,
+            )
+        });
+        fields.add(ui, "Stroke", "visuals.window_stroke", |title| {
+            stroke_picker(
+                title,
+                &mut visuals.window_stroke,
+                self.visuals_default.window_stroke,
+            )
+        });
+        fields.add(
+            ui,
+            "Highlight Topmost",
+            "visuals.window_highlight_topmost",
+            |title| {
+                bool_picker(
+                    title,
+                    &mut visuals.window_highlight_topmost,
+                    self.visuals_default.window_highlight_topmost,
+                )
+            },
+        );
     }
 
-    fn colors(&self, ui: &mut Ui, visuals: &mut Visuals) {
-        ui.add(color_picker(
-            "Hyperlink",
-            &mut visuals.hyperlink_color,
-            self.visuals_default.hyperlink_color,
-        ));
-        ui.add(color_picker(
-            "Faint Background",
-            &mut visuals.faint_bg_color,
-            self.visuals_default.faint_bg_color,
-        ));
-        ui.add(color_picker(
+    fn colors(&self, ui: &mut Ui, fields: &mut Fields, visuals: &mut Visuals) {
+        fields.add(ui, "Hyperlink", "visuals.hyperlink_color", |title| {
+            color_picker(
+                title,
+                &mut visuals.hyperlink_color,
+                self.visuals_default.hyperlink_color,
+            )
+        });
+        fields.add(ui, "Faint Background", "visuals.faint_bg_color", |title| {
+            color_picker(
+                title,
+                &mut visuals.faint_bg_color,
+                self.visuals_default.faint_bg_color,
+            )
+        });
+        fields.add(
+            ui,
             "Extreme Background",
-            &mut visuals.extreme_bg_color,
-            self.visuals_default.extreme_bg_color,
-        ));
-        ui.add(color_picker(
-            "Code Background",
-            &mut visuals.code_bg_color,
-            self.visuals_default.code_bg_color,
-        ));
-        ui.add(color_picker(
-            "Warning Foreground",
-            &mut visuals.warn_fg_color,
-            self.visuals_default.warn_fg_color,
-        ));
-        ui.add(color_picker(
-            "Error Foreground",
-            &mut visuals.error_fg_color,
-            self.visuals_default.error_fg_color,
-        ));
+            "visuals.extreme_bg_color",
+            |title| {
+                color_picker(
+                    title,
+                    &mut visuals.extreme_bg_color,
+                    self.visuals_default.extreme_bg_color,
+                )
+            },
+        );
+        fields.add(ui, "Code Background", "visuals.code_bg_color", |title| {
+            color_picker(
+                title,
+                &mut visuals.code_bg_color,
+                self.visuals_default.code_bg_color,
+            )
+        });
+        fields.add(ui, "Warning Foreground", "visuals.warn_fg_color", |title| {
+            color_picker(
+                title,
+                &mut visuals.warn_fg_color,
+                self.visuals_default.warn_fg_color,
+            )
+        });
+        fields.add(ui, "Error Foreground", "visuals.error_fg_color", |title| {
+            color_picker(
+                title,
+                &mut visuals.error_fg_color,
+                self.visuals_default.error_fg_color,
+            )
+        });
     }
 
-    fn widgets(&mut self, ui: &mut Ui, visuals: &mut Visuals) {
-        self.widget_tab_state.show(ui);
-
-        let visuals: &mut WidgetVisuals = match self.widget_tab_state {
-            WidgetTabState::NonInteractive => &mut visuals.widgets.noninteractive,
-            WidgetTabState::Inactive => &mut visuals.widgets.inactive,
-            WidgetTabState::Hovered => &mut visuals.widgets.hovered,
-            WidgetTabState::Active => &mut visuals.widgets.active,
-            WidgetTabState::Open => &mut visuals.widgets.open,
-        };
+    fn widgets(
+        &mut self,
+        ui: &mut Ui,
+        fields: &mut Fields,
+        visuals: &mut Visuals,
+        state: WidgetTabState,
+    ) {
+        let prefix = format!("visuals.widgets.{}", state.field());
 
-        let visuals_default: WidgetVisuals = match self.widget_tab_state {
-            WidgetTabState::NonInteractive => self.visuals_default.widgets.noninteractive,
-            WidgetTabState::Inactive => self.visuals_default.widgets.inactive,
-            WidgetTabState::Hovered => self.visuals_default.widgets.hovered,
-            WidgetTabState::Active => self.visuals_default.widgets.active,
-            WidgetTabState::Open => self.visuals_default.widgets.open,
-        };
+        let visuals: &mut WidgetVisuals = state.widget_mut(&mut visuals.widgets);
+        let visuals_default: WidgetVisuals = *state.widget(&self.visuals_default.widgets);
 
-        ui.add(color_picker(
+        fields.add(
+            ui,
             "Background Fill",
-            &mut visuals.bg_fill,
-            visuals_default.bg_fill,
-        ));
-        ui.add(color_picker(
+            &format!("{prefix}.bg_fill"),
+            |title| color_picker(title, &mut visuals.bg_fill, visuals_default.bg_fill),
+        );
+        fields.add(
+            ui,
             "Weak Background Fill",
-            &mut visuals.weak_bg_fill,
-            visuals_default.weak_bg_fill,
-        ));
-        ui.add(stroke_picker(
+            &format!("{prefix}.weak_bg_fill"),
+            |title| {
+                color_picker(
+                    title,
+                    &mut visuals.weak_bg_fill,
+                    visuals_default.weak_bg_fill,
+                )
+            },
+        );
+        fields.add(
+            ui,
             "Background Stroke",
-            &mut visuals.bg_stroke,
-            visuals_default.bg_stroke,
-        ));
-
-        let rounding_uniform: &mut (bool, f32) = match self.widget_tab_state {
-            WidgetTabState::NonInteractive => &mut self.noninteractive_rounding,
-            WidgetTabState::Inactive => &mut self.inactive_rounding,
-            WidgetTabState::Hovered => &mut self.hovered_rounding,
-            WidgetTabState::Active => &mut self.active_rounding,
-            WidgetTabState::Open => &mut self.open_rounding,
-        };
-
-        let rounding_uniform_default: (bool, f32) = match self.widget_tab_state {
-            WidgetTabState::NonInteractive => Self::default().noninteractive_rounding,
-            WidgetTabState::Inactive => Self::default().inactive_rounding,
-            WidgetTabState::Hovered => Self::default().hovered_rounding,
-            WidgetTabState::Active => Self::default().active_rounding,
-            WidgetTabState::Open => Self::default().open_rounding,
-        };
-
-        ui.add(rounding_picker(
-            "Rounding",
-            rounding_uniform,
-            &mut visuals.rounding,
-            (visuals_default.rounding, rounding_uniform_default),
-        ));
-        ui.add(stroke_picker(
+            &format!("{prefix}.bg_stroke"),
+            |title| stroke_picker(title, &mut visuals.bg_stroke, visuals_default.bg_stroke),
+        );
+
+        let rounding_uniform = self.rounding_uniform(state);
+        let rounding_uniform_default = uniform(visuals_default.rounding);
+
+        fields.add(ui, "Rounding", &format!("{prefix}.rounding"), |title| {
+            rounding_picker(
+                title,
+                rounding_uniform,
+                &mut visuals.rounding,
+                (visuals_default.rounding, rounding_uniform_default),
+            )
+        });
+        fields.add(
+            ui,
             "Foreground Stroke",
-            &mut visuals.fg_stroke,
-            visuals_default.fg_stroke,
-        ));
-        ui.add(float_picker(
-            "Expansion",
-            &mut visuals.expansion,
-            visuals_default.expansion,
-        ));
+            &format!("{prefix}.fg_stroke"),
+            |title| stroke_picker(title, &mut visuals.fg_stroke, visuals_default.fg_stroke),
+        );
+        fields.add(ui, "Expansion", &format!("{prefix}.expansion"), |title| {
+            float_picker(title, &mut visuals.expansion, visuals_default.expansion)
+        });
     }
 }
 
@@ -282,6 +715,17 @@ enum TabState {
 }
 
 impl TabState {
+    const ALL: [Self; 4] = [Self::Misc, Self::Window, Self::Colors, Self::Widgets];
+
+    fn name(self) -> &'static str {
+        match self {
+            TabState::Misc => "Misc",
+            TabState::Window => "Window",
+            TabState::Colors => "Colors",
+            TabState::Widgets => "Widgets",
+        }
+    }
+
     fn show(&mut self, ui: &mut Ui) -> Response {
         let resp = ui.columns(4, |cols| {
             let misc = cols[0].add_enabled(*self != Self::Misc, Button::new("Misc"));
@@ -323,6 +767,68 @@ enum WidgetTabState {
 }
 
 impl WidgetTabState {
+    const ALL: [Self; 5] = [
+        Self::NonInteractive,
+        Self::Inactive,
+        Self::Hovered,
+        Self::Active,
+        Self::Open,
+    ];
+
+    /// The states "Derive From Inactive" replaces.
+    const DERIVED: [Self; 3] = [Self::Hovered, Self::Active, Self::Open];
+
+    fn name(self) -> &'static str {
+        match self {
+            WidgetTabState::NonInteractive => "NonInteractive",
+            WidgetTabState::Inactive => "Inactive",
+            WidgetTabState::Hovered => "Hovered",
+            WidgetTabState::Active => "Active",
+            WidgetTabState::Open => "Open",
+        }
+    }
+
+    fn field(self) -> &'static str {
+        match self {
+            WidgetTabState::NonInteractive => "noninteractive",
+            WidgetTabState::Inactive => "inactive",
+            WidgetTabState::Hovered => "hovered",
+            WidgetTabState::Active => "active",
+            WidgetTabState::Open => "open",
+        }
+    }
+
+    fn widget(self, widgets: &Widgets) -> &WidgetVisuals {
+        match self {
+            WidgetTabState::NonInteractive => &widgets.noninteractive,
+            WidgetTabState::Inactive => &widgets.inactive,
+            WidgetTabState::Hovered => &widgets.hovered,
+            WidgetTabState::Active => &widgets.active,
+            WidgetTabState::Open => &widgets.open,
+        }
+    }
+
+    fn widget_mut(self, widgets: &mut Widgets) -> &mut WidgetVisuals {
+        match self {
+            WidgetTabState::NonInteractive => &mut widgets.noninteractive,
+            WidgetTabState::Inactive => &mut widgets.inactive,
+            WidgetTabState::Hovered => &mut widgets.hovered,
+            WidgetTabState::Active => &mut widgets.active,
+            WidgetTabState::Open => &mut widgets.open,
+        }
+    }
+
+    fn from_field(field: &str) -> Option<Self> {
+        match field {
+            "noninteractive" => Some(WidgetTabState::NonInteractive),
+            "inactive" => Some(WidgetTabState::Inactive),
+            "hovered" => Some(WidgetTabState::Hovered),
+            "active" => Some(WidgetTabState::Active),
+            "open" => Some(WidgetTabState::Open),
+            _ => None,
+        }
+    }
+
     fn show(&mut self, ui: &mut Ui) -> Response {
         let resp = ui.columns(3, |cols| {
             let noninteractive =
@@ -360,14 +866,46 @@ impl WidgetTabState {
     }
 }
 
-fn dark_light_mode_picker(mode: &mut bool) -> impl Widget + '_ {
+/// How a widget state is made from Inactive.
+#[derive(Copy, Clone)]
+struct Derivation {
+    /// Moves colors toward white, or toward black if negative.
+    lighten: f32,
+    /// Added to the expansion.
+    expand: f32,
+}
+
+impl Derivation {
+    fn apply(self, inactive: WidgetVisuals) -> WidgetVisuals {
+        let lighten = |color: Color32| {
+            let [r, g, b, a] = color.to_srgba_unmultiplied();
+            let shift = |c: u8| {
+                let c = c as f32;
+                let shifted = if self.lighten >= 0.0 {
+                    c + (255.0 - c) * self.lighten
+                } else {
+                    c * (1.0 + self.lighten)
+                };
+                shifted.round().clamp(0.0, 255.0) as u8
+            };
+            Color32::from_rgba_unmultiplied(shift(r), shift(g), shift(b), a)
+        };
+
+        let mut derived = inactive;
+        derived.bg_fill = lighten(inactive.bg_fill);
+        derived.weak_bg_fill = lighten(inactive.weak_bg_fill);
+        derived.bg_stroke.color = lighten(inactive.bg_stroke.color);
+        derived.fg_stroke.color = lighten(inactive.fg_stroke.color);
+        derived.expansion = inactive.expansion + self.expand;
+        derived
+    }
+}
+
+fn dark_light_mode_picker<'a>(title: &'a str, mode: &'a mut bool) -> impl Widget + 'a {
     move |ui: &mut Ui| {
         picker_frame(ui, |ui: &mut Ui| {
             ui.horizontal(|ui| {
-                if ui
-                    .add(Label::new("Dark Mode").sense(Sense::click()))
-                    .clicked()
-                {
+                if ui.add(Label::new(title).sense(Sense::click())).clicked() {
                     *mode = !*mode;
                 };
                 ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
@@ -379,3 +917,63 @@ fn dark_light_mode_picker(mode: &mut bool) -> impl Widget + '_ {
         })
     }
 }
+
+fn handle_shape_picker<'a>(
+    title: &'a str,
+    shape: &'a mut HandleShape,
+    default: HandleShape,
+) -> impl Widget + 'a {
+    move |ui: &mut Ui| {
+        picker_frame(ui, |ui: &mut Ui| {
+            ui.horizontal(|ui| {
+                ui.label(title);
+                ui.with_layout(Layout::right_to_left(Align::Min), |ui| {
+                    if ui
+                        .add_enabled(*shape != default, Button::new("⟲"))
+                        .clicked()
+                    {
+                        *shape = default;
+                    }
+
+                    if let HandleShape::Rect { aspect_ratio } = shape {
+                        ui.add(
+                            DragValue::new(aspect_ratio)
+                                .range(0.1..=10.0)
+                                .min_decimals(1)
+                                .speed(0.01)
+                                .prefix("Aspect Ratio: "),
+                        );
+                    }
+
+                    ComboBox::from_id_source(title)
+                        .selected_text(match shape {
+                            HandleShape::Circle => "Circle",
+                            HandleShape::Rect { .. } => "Rect",
+                        })
+                        .show_ui(ui, |ui| {
+                            if ui
+                                .selectable_label(matches!(shape, HandleShape::Circle), "Circle")
+                                .clicked()
+                            {
+                                *shape = HandleShape::Circle;
+                            }
+                            if ui
+                                .selectable_label(matches!(shape, HandleShape::Rect { .. }), "Rect")
+                                .clicked()
+                                && !matches!(shape, HandleShape::Rect { .. })
+                            {
+                                *shape = HandleShape::Rect { aspect_ratio: 0.5 };
+                            }
+                        });
+                });
+            })
+            .response
+        })
+    }
+}
+
+/// The state of a rounding picker's Uniform option showing `rounding`.
+fn uniform(rounding: Rounding) -> (bool, f32) {
+    let Rounding { nw, ne, sw, se } = rounding;
+    (nw == ne && ne == sw && sw == se, nw)
+}
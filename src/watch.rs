@@ -0,0 +1,104 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant, SystemTime},
+};
+
+use eframe::{
+    egui::{Context, Layout, Ui},
+    emath::Align,
+};
+
+/// How often the watched file's modification time is checked.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The last imported file, shared with the import dialog's thread.
+pub type WatchedFile = Arc<Mutex<Option<Watched>>>;
+
+pub struct Watched {
+    path: PathBuf,
+    /// The modification time of the version last loaded.
+    modified: Option<SystemTime>,
+}
+
+impl Watched {
+    /// A file whose current version was just imported.
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            modified: modified(&path),
+            path,
+        }
+    }
+}
+
+/// Re-imports the last imported file whenever it's saved, so it can be
+/// hand-edited with the preview updating live.
+#[derive(Default)]
+pub struct FileWatch {
+    enabled: bool,
+    file: WatchedFile,
+    /// When the modification time was last checked.
+    checked: Option<Instant>,
+}
+
+impl FileWatch {
+    pub fn file(&self) -> WatchedFile {
+        self.file.clone()
+    }
+
+    pub fn ui(&mut self, ui: &mut Ui) {
+        let path = self.file.lock().unwrap().as_ref().map(|f| f.path.clone());
+
+        ui.horizontal(|ui| {
+            ui.label("Watch File");
+            ui.with_layout(Layout::right_to_left(Align::Min), |ui| {
+                ui.add_enabled_ui(path.is_some(), |ui| {
+                    ui.checkbox(&mut self.enabled, "")
+                        .on_hover_text("Reload the imported file whenever it's saved.")
+                        .on_disabled_hover_text("Import a file to watch it.");
+                });
+
+                if let Some(path) = &path {
+                    let name = path.file_name().unwrap_or(path.as_os_str());
+                    ui.weak(name.to_string_lossy())
+                        .on_hover_text(path.display().to_string());
+                }
+            });
+        });
+    }
+
+    /// Returns the watched file's path and contents when it has changed
+    /// since it was last loaded.
+    pub fn poll(&mut self, ctx: &Context) -> Option<(PathBuf, Result<Vec<u8>, String>)> {
+        if !self.enabled {
+            return None;
+        }
+        let mut file = self.file.lock().unwrap();
+        let file = file.as_mut()?;
+
+        // frames come much faster than saves while dragging a slider
+        if let Some(waited) = self.checked.map(|checked| checked.elapsed()) {
+            if waited < POLL_INTERVAL {
+                ctx.request_repaint_after(POLL_INTERVAL - waited);
+                return None;
+            }
+        }
+        self.checked = Some(Instant::now());
+        ctx.request_repaint_after(POLL_INTERVAL);
+
+        let modified = modified(&file.path);
+        if modified == file.modified {
+            return None;
+        }
+        // even if this version is broken, wait for the next save to retry
+        file.modified = modified;
+
+        let bytes = fs::read(&file.path).map_err(|e| e.to_string());
+        Some((file.path.clone(), bytes))
+    }
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
@@ -0,0 +1,182 @@
+use std::{
+    fs,
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+use eframe::{
+    egui::{Button, Context, Layout, Style, Ui},
+    emath::Align,
+};
+use egui_notify::Toasts;
+use serde_json::Value;
+
+use crate::export::ExportFormat;
+
+/// How long the style has to stay the same before the file is rewritten, so
+/// dragging a slider doesn't write on every frame.
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Keeps an exported file in sync with the style, for apps that hot-reload it.
+#[derive(Default)]
+pub struct WriteBack {
+    binding: Option<Binding>,
+}
+
+struct Binding {
+    path: PathBuf,
+    format: ExportFormat,
+    /// The style last written to `path`, as JSON: comparing `Style`s would
+    /// count a new number formatter as a change, so every import would write.
+    written: Option<Value>,
+    /// An edited style and when it was last changed.
+    pending: Option<(Value, Instant)>,
+}
+
+impl WriteBack {
+    /// Shows the bound file, returning true if the user asked to bind one.
+    pub fn ui(&mut self, ui: &mut Ui, format: ExportFormat) -> bool {
+        let (mut bind, mut unbind) = (false, false);
+
+        ui.horizontal(|ui| {
+            ui.label("Write Back").on_hover_text(
+                "Rewrite a file whenever the style changes, for apps that hot-reload their theme.",
+            );
+            ui.with_layout(Layout::right_to_left(Align::Min), |ui| {
+                match &self.binding {
+                    Some(binding) => {
+                        unbind = ui.add(Button::new("🗙")).on_hover_text("Stop").clicked();
+                        let name = binding.path.file_name().unwrap_or(binding.path.as_os_str());
+                        ui.weak(format!(
+                            "{} ({})",
+                            name.to_string_lossy(),
+                            binding.format.name()
+                        ))
+                        .on_hover_text(binding.path.display().to_string());
+                    }
+                    None => {
+                        bind = ui
+                            .add_enabled(format != ExportFormat::Crate, Button::new("Bind File…"))
+                            .on_hover_text(format!("Keep a {} file in sync.", format.name()))
+                            .on_disabled_hover_text("Crates can't be written back.")
+                            .clicked();
+                    }
+                }
+            });
+        });
+
+        if unbind {
+            self.binding = None;
+        }
+        bind
+    }
+
+    /// Ask for a file to keep in sync as `format`.
+    pub fn bind(&mut self, format: ExportFormat) {
+        let Some(path) = rfd::FileDialog::new()
+            .set_file_name(format!("style.{}", format.extension()))
+            .add_filter(format.name(), &[format.extension()])
+            .save_file()
+        else {
+            return;
+        };
+
+        self.binding = Some(Binding {
+            path,
+            format,
+            written: None,
+            pending: None,
+        });
+    }
+
+    /// Rewrite the bound file with `generate` once `style` has settled.
+    pub fn poll(
+        &mut self,
+        ctx: &Context,
+        style: &Style,
+        toasts: &mut Toasts,
+        generate: impl FnOnce(ExportFormat, &Style) -> Result<String, String>,
+    ) {
+        let Some(binding) = &mut self.binding else {
+            return;
+        };
+
+        let value = serde_json::to_value(style).ok();
+        if binding.written.is_some() && binding.written == value {
+            binding.pending = None;
+            return;
+        }
+
+        let changed_at = match &binding.pending {
+            Some((pending, changed_at)) if Some(pending) == value.as_ref() => *changed_at,
+            _ => {
+                binding.pending = value.clone().map(|value| (value, Instant::now()));
+                Instant::now()
+            }
+        };
+
+        let waited = changed_at.elapsed();
+        if waited < DEBOUNCE {
+            ctx.request_repaint_after(DEBOUNCE - waited);
+            return;
+        }
+
+        binding.pending = None;
+        binding.written = value;
+
+        let written = generate(binding.format, style)
+            .and_then(|contents| fs::write(&binding.path, contents).map_err(|e| e.to_string()));
+        if let Err(err) = written {
+            toasts
+                .error(format!("Write Back Error: {err}"))
+                .set_duration(Some(Duration::from_secs(5)));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use egui_themer::migrate;
+
+    use super::*;
+
+    fn generate(_: ExportFormat, style: &Style) -> Result<String, String> {
+        serde_json::to_string_pretty(style).map_err(|e| e.to_string())
+    }
+
+    /// A bound file that's also watched is reloaded after every write, which
+    /// mustn't count as another change.
+    #[test]
+    fn reloading_the_bound_file_doesnt_rewrite_it() {
+        let path = std::env::temp_dir().join(format!("write_back_{}.json", std::process::id()));
+        let mut write_back = WriteBack {
+            binding: Some(Binding {
+                path: path.clone(),
+                format: ExportFormat::Json,
+                written: None,
+                pending: None,
+            }),
+        };
+        let (ctx, mut toasts) = (Context::default(), Toasts::default());
+
+        let mut style = Style::default();
+        style.visuals.window_rounding = 3.0.into();
+        write_back.poll(&ctx, &style, &mut toasts, generate);
+        thread::sleep(DEBOUNCE);
+        write_back.poll(&ctx, &style, &mut toasts, generate);
+        let modified = fs::metadata(&path).unwrap().modified().unwrap();
+
+        let reloaded = migrate::import_style(&fs::read(&path).unwrap())
+            .unwrap()
+            .style;
+        write_back.poll(&ctx, &reloaded, &mut toasts, generate);
+        thread::sleep(DEBOUNCE);
+        write_back.poll(&ctx, &reloaded, &mut toasts, generate);
+
+        assert_eq!(fs::metadata(&path).unwrap().modified().unwrap(), modified);
+        assert!(write_back.binding.unwrap().pending.is_none());
+        fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,156 @@
+use eframe::egui::Style;
+use egui_themer::{
+    clipboard::{self, Format},
+    codegen::{self, Section, SourceOptions},
+    share,
+};
+use pretty_assertions::assert_eq;
+
+mod common;
+
+fn json(style: &Style) -> String {
+    serde_json::to_string_pretty(style).unwrap()
+}
+
+#[test]
+fn rust_round_trip() {
+    for seed in 0..8 {
+        let style = common::random_style(seed);
+        let options = SourceOptions {
+            lazy_static: true,
+            apply: Some(1.5),
+            ..Default::default()
+        };
+
+        let source = codegen::generate_source(&style, &options).unwrap();
+        let (format, migrated) = clipboard::paste(&source, &Style::default()).unwrap();
+        assert_eq!(format, Format::Rust(None));
+        assert_eq!(json(&migrated.style), json(&style));
+
+        for section in Section::ALL {
+            let snippet = codegen::generate_snippet(&style, section, &options).unwrap();
+            let (format, migrated) = clipboard::paste(&snippet, &style).unwrap();
+            assert_eq!(format, Format::Rust(Some(section)));
+            assert_eq!(json(&migrated.style), json(&style));
+        }
+    }
+}
+
+#[test]
+fn sections_keep_the_rest() {
+    let pasted = common::random_style(1);
+    let current = common::random_style(2);
+    let snippet =
+        codegen::generate_snippet(&pasted, Section::Widgets, &SourceOptions::default()).unwrap();
+
+    let (_, migrated) = clipboard::paste(&snippet, &current).unwrap();
+    let mut expected = current.clone();
+    expected.visuals.widgets = pasted.visuals.widgets.clone();
+    assert_eq!(json(&migrated.style), json(&expected));
+}
+
+#[test]
+fn detects_json() {
+    let style = common::random_style(3);
+    let current = Style::default();
+
+    let (format, migrated) = clipboard::paste(&json(&style), &current).unwrap();
+    assert_eq!(format, Format::Json(None));
+    assert_eq!(json(&migrated.style), json(&style));
+
+    let widgets = serde_json::to_string(&style.visuals.widgets).unwrap();
+    let (format, migrated) = clipboard::paste(&widgets, &current).unwrap();
+    assert_eq!(format, Format::Json(Some(Section::Widgets)));
+    assert_eq!(
+        serde_json::to_string(&migrated.style.visuals.widgets).unwrap(),
+        widgets
+    );
+
+    let link = share::link(share::LIVE_URL, &style).unwrap();
+    let (format, migrated) = clipboard::paste(&link, &current).unwrap();
+    assert_eq!(format, Format::ShareLink);
+    assert_eq!(json(&migrated.style), json(&style));
+}
+
+#[test]
+fn hand_written_rust() {
+    let snippet = "
+        // tweaked by hand
+        egui::style::Selection {
+            bg_fill: Color32::from_rgb(10, 20, 30),
+            stroke: Stroke::new(2.0, Color32::WHITE),
+        }
+    ";
+
+    let (format, migrated) = clipboard::paste(snippet, &Style::default()).unwrap();
+    assert_eq!(format, Format::Rust(Some(Section::Selection)));
+    let selection = migrated.style.visuals.selection;
+    assert_eq!(
+        selection.bg_fill,
+        eframe::egui::Color32::from_rgb(10, 20, 30)
+    );
+    assert_eq!(
+        selection.stroke,
+        eframe::egui::Stroke::new(2.0, eframe::egui::Color32::WHITE)
+    );
+
+    assert!(clipboard::paste("hello world", &Style::default()).is_err());
+}
+
+#[test]
+fn strings_and_attributes() {
+    let expected = "Selection { bg_fill: Color32::from_rgb(10, 20, 30), stroke: Stroke::NONE }";
+    for prelude in [
+        r#"const NAME: &str = "a \"b\" Selection { bg_fill: Color32::RED }";"#,
+        r##"const NAME: &str = r#"a "b" Selection { bg_fill: Color32::RED }"#;"##,
+        r#"const NAME: &[u8] = br"a \"; const B: &str = "Selection { bg_fill: Color32::RED }";"#,
+        r#"#[doc = "x]"] const NAME: &str = "Selection { bg_fill: Color32::RED }";"#,
+        r#"#[cfg_attr(all(), doc = "]")] #[allow(dead_code)] fn f() {}"#,
+        "#![allow(clippy::excessive_precision)] #[doc = r#\"\"]\"#]",
+    ] {
+        let snippet = format!("{prelude}\n{expected}");
+        let (format, migrated) = clipboard::paste(&snippet, &Style::default())
+            .unwrap_or_else(|e| panic!("{prelude}: {e}"));
+        assert_eq!(format, Format::Rust(Some(Section::Selection)), "{prelude}");
+        assert_eq!(
+            migrated.style.visuals.selection.bg_fill,
+            eframe::egui::Color32::from_rgb(10, 20, 30),
+            "{prelude}"
+        );
+    }
+
+    for unclosed in [
+        "#[doc = \"] Selection { bg_fill: Color32::RED }",
+        "#[doc = [x] Selection { bg_fill: Color32::RED }",
+        "r#\"Selection { bg_fill: Color32::RED }\"",
+    ] {
+        assert!(
+            clipboard::paste(unclosed, &Style::default()).is_err(),
+            "{unclosed} should be rejected"
+        );
+    }
+}
+
+#[test]
+fn malformed_rust() {
+    for snippet in [
+        "Selection { bg_fill: Color32::WHITE, stroke: Stroke::new(1.0) }",
+        "Selection { bg_fill: Color32::WHITE, stroke: Stroke::new() }",
+        "Selection { bg_fill: Color32::from_rgb(1, 2), stroke: Stroke::NONE }",
+        "Selection { bg_fill: Color32::from_rgb(1, 2, 300), stroke: Stroke::NONE }",
+        "Selection { bg_fill: Color32::from_gray(), stroke: Stroke::NONE }",
+        "Spacing { item_spacing: Vec2::new(1.0), ..Default::default() }",
+        "Spacing { item_spacing: vec2(), ..Default::default() }",
+        "Spacing { window_margin: Margin::symmetric(1.0), ..Default::default() }",
+        "Spacing { window_margin: Margin::same(), ..Default::default() }",
+        "Visuals { override_text_color: Some(), ..Default::default() }",
+        "Visuals { window_rounding: Rounding::same(), ..Default::default() }",
+        "Selection { bg_fill: Color32::WHITE, stroke: Stroke::new(1.0, ",
+        "Selection { bg_fill: Color32::WHITE, stroke: Stroke::new(1.0, Color32::WHITE",
+    ] {
+        assert!(
+            clipboard::paste(snippet, &Style::default()).is_err(),
+            "{snippet} should be rejected"
+        );
+    }
+}
@@ -0,0 +1,307 @@
+#![allow(dead_code)]
+
+use eframe::egui::{
+    style::{HandleShape, Interaction, NumericColorSpace, ScrollStyle, Spacing, WidgetVisuals},
+    Color32, CursorIcon, Margin, Rounding, Shadow, Stroke, Style, TextWrapMode, Vec2, Visuals,
+};
+use proptest::{
+    prelude::*,
+    sample::select,
+    strategy::ValueTree,
+    test_runner::{Config, RngAlgorithm, TestRng, TestRunner},
+};
+
+/// Any style, filling every field the exporters cover.
+///
+/// Fonts, text styles, the number formatter and debug options aren't
+/// exported, so they're left at their defaults.
+pub fn style() -> impl Strategy<Value = Style> {
+    (
+        visuals(),
+        spacing(),
+        interaction(),
+        select(vec![
+            None,
+            Some(TextWrapMode::Extend),
+            Some(TextWrapMode::Wrap),
+            Some(TextWrapMode::Truncate),
+        ]),
+        float(),
+        any::<[bool; 3]>(),
+    )
+        .prop_map(
+            |(visuals, spacing, interaction, wrap_mode, animation_time, flags)| {
+                let [explanation_tooltips, url_in_tooltip, always_scroll_the_only_direction] =
+                    flags;
+                Style {
+                    visuals,
+                    spacing,
+                    interaction,
+                    wrap_mode,
+                    animation_time,
+                    explanation_tooltips,
+                    url_in_tooltip,
+                    always_scroll_the_only_direction,
+                    ..Default::default()
+                }
+            },
+        )
+}
+
+/// A style drawn from [`style`] with a fixed seed, for tests that need a
+/// handful of styles rather than a property.
+pub fn random_style(seed: u64) -> Style {
+    let rng = TestRng::from_seed(RngAlgorithm::ChaCha, &[seed.to_le_bytes(); 4].concat());
+    let mut runner = TestRunner::new_with_rng(Config::default(), rng);
+    style().new_tree(&mut runner).unwrap().current()
+}
+
+/// A size, kept within what a theme would use so it reads back exactly
+/// from every format.
+fn float() -> impl Strategy<Value = f32> {
+    0.0f32..=1000.0
+}
+
+fn signed() -> impl Strategy<Value = f32> {
+    -1000.0f32..=1000.0
+}
+
+fn unit() -> impl Strategy<Value = f32> {
+    0.0f32..=1.0
+}
+
+/// A color any unmultiplied color picker could produce, so never brighter than
+/// its alpha.
+fn color() -> impl Strategy<Value = Color32> {
+    any::<[u8; 4]>().prop_map(|[r, g, b, a]| Color32::from_rgba_unmultiplied(r, g, b, a))
+}
+
+fn vec2() -> impl Strategy<Value = Vec2> {
+    (float(), float()).prop_map(|(x, y)| Vec2::new(x, y))
+}
+
+fn margin() -> impl Strategy<Value = Margin> {
+    [float(), float(), float(), float()].prop_map(|[left, right, top, bottom]| Margin {
+        left,
+        right,
+        top,
+        bottom,
+    })
+}
+
+fn rounding() -> impl Strategy<Value = Rounding> {
+    [float(), float(), float(), float()].prop_map(|[nw, ne, sw, se]| Rounding { nw, ne, sw, se })
+}
+
+fn stroke() -> impl Strategy<Value = Stroke> {
+    (float(), color()).prop_map(|(width, color)| Stroke::new(width, color))
+}
+
+fn shadow() -> impl Strategy<Value = Shadow> {
+    (vec2_signed(), float(), float(), color()).prop_map(|(offset, blur, spread, color)| Shadow {
+        offset,
+        blur,
+        spread,
+        color,
+    })
+}
+
+fn vec2_signed() -> impl Strategy<Value = Vec2> {
+    (signed(), signed()).prop_map(|(x, y)| Vec2::new(x, y))
+}
+
+fn widget_visuals() -> impl Strategy<Value = WidgetVisuals> {
+    (color(), color(), stroke(), rounding(), stroke(), signed()).prop_map(
+        |(bg_fill, weak_bg_fill, bg_stroke, rounding, fg_stroke, expansion)| WidgetVisuals {
+            bg_fill,
+            weak_bg_fill,
+            bg_stroke,
+            rounding,
+            fg_stroke,
+            expansion,
+        },
+    )
+}
+
+fn spacing() -> impl Strategy<Value = Spacing> {
+    (
+        [vec2(), vec2(), vec2(), vec2()],
+        [margin(), margin()],
+        prop::array::uniform12(float()),
+        any::<bool>(),
+        scroll(),
+    )
+        .prop_map(|(vec2s, margins, floats, indent_line, scroll)| {
+            let [item_spacing, button_padding, interact_size, default_area_size] = vec2s;
+            let [window_margin, menu_margin] = margins;
+            let [indent, slider_width, slider_rail_height, combo_width, text_edit_width, icon_width, icon_width_inner, icon_spacing, tooltip_width, menu_width, menu_spacing, combo_height] =
+                floats;
+            Spacing {
+                item_spacing,
+                window_margin,
+                button_padding,
+                menu_margin,
+                indent,
+                interact_size,
+                slider_width,
+                slider_rail_height,
+                combo_width,
+                text_edit_width,
+                icon_width,
+                icon_width_inner,
+                icon_spacing,
+                default_area_size,
+                tooltip_width,
+                menu_width,
+                menu_spacing,
+                indent_ends_with_horizontal_line: indent_line,
+                combo_height,
+                scroll,
+            }
+        })
+}
+
+fn scroll() -> impl Strategy<Value = ScrollStyle> {
+    (
+        any::<[bool; 2]>(),
+        prop::array::uniform6(float()),
+        prop::array::uniform6(unit()),
+    )
+        .prop_map(|(flags, sizes, opacities)| {
+            let [floating, foreground_color] = flags;
+            let [bar_width, handle_min_length, bar_inner_margin, bar_outer_margin, floating_width, floating_allocated_width] =
+                sizes;
+            let [dormant_background_opacity, active_background_opacity, interact_background_opacity, dormant_handle_opacity, active_handle_opacity, interact_handle_opacity] =
+                opacities;
+            ScrollStyle {
+                floating,
+                bar_width,
+                handle_min_length,
+                bar_inner_margin,
+                bar_outer_margin,
+                floating_width,
+                floating_allocated_width,
+                foreground_color,
+                dormant_background_opacity,
+                active_background_opacity,
+                interact_background_opacity,
+                dormant_handle_opacity,
+                active_handle_opacity,
+                interact_handle_opacity,
+            }
+        })
+}
+
+fn interaction() -> impl Strategy<Value = Interaction> {
+    (prop::array::uniform5(float()), any::<[bool; 3]>()).prop_map(|(floats, flags)| {
+        let [interact_radius, resize_grab_radius_side, resize_grab_radius_corner, tooltip_delay, tooltip_grace_time] =
+            floats;
+        let [show_tooltips_only_when_still, selectable_labels, multi_widget_text_select] = flags;
+        Interaction {
+            interact_radius,
+            resize_grab_radius_side,
+            resize_grab_radius_corner,
+            show_tooltips_only_when_still,
+            tooltip_delay,
+            tooltip_grace_time,
+            selectable_labels,
+            multi_widget_text_select,
+        }
+    })
+}
+
+fn visuals() -> impl Strategy<Value = Visuals> {
+    (
+        (
+            any::<bool>(),
+            prop::option::of(color()),
+            [
+                widget_visuals(),
+                widget_visuals(),
+                widget_visuals(),
+                widget_visuals(),
+                widget_visuals(),
+            ],
+            (color(), stroke()),
+        ),
+        (
+            prop::array::uniform8(color()),
+            [rounding(), rounding()],
+            [shadow(), shadow()],
+            [stroke(), stroke()],
+        ),
+        (
+            prop::array::uniform4(float()),
+            any::<[bool; 9]>(),
+            prop::option::of(float()),
+            prop::option::of(select(CursorIcon::ALL.to_vec())),
+            select(vec![
+                NumericColorSpace::GammaByte,
+                NumericColorSpace::Linear,
+            ]),
+        ),
+    )
+        .prop_map(|(widgets, window, misc)| {
+            let (dark_mode, override_text_color, states, (selection_fill, selection_stroke)) =
+                widgets;
+            let (colors, [window_rounding, menu_rounding], shadows, strokes) = window;
+            let (floats, flags, handle_aspect_ratio, interact_cursor, numeric_color_space) = misc;
+
+            let mut visuals = if dark_mode {
+                Visuals::dark()
+            } else {
+                Visuals::light()
+            };
+
+            visuals.override_text_color = override_text_color;
+            let [noninteractive, inactive, hovered, active, open] = states;
+            visuals.widgets.noninteractive = noninteractive;
+            visuals.widgets.inactive = inactive;
+            visuals.widgets.hovered = hovered;
+            visuals.widgets.active = active;
+            visuals.widgets.open = open;
+            visuals.selection.bg_fill = selection_fill;
+            visuals.selection.stroke = selection_stroke;
+
+            [
+                visuals.hyperlink_color,
+                visuals.faint_bg_color,
+                visuals.extreme_bg_color,
+                visuals.code_bg_color,
+                visuals.warn_fg_color,
+                visuals.error_fg_color,
+                visuals.window_fill,
+                visuals.panel_fill,
+            ] = colors;
+            visuals.window_rounding = window_rounding;
+            visuals.menu_rounding = menu_rounding;
+            [visuals.window_shadow, visuals.popup_shadow] = shadows;
+            [visuals.window_stroke, visuals.text_cursor.stroke] = strokes;
+
+            [
+                visuals.resize_corner_size,
+                visuals.text_cursor.on_duration,
+                visuals.text_cursor.off_duration,
+                visuals.clip_rect_margin,
+            ] = floats;
+            [
+                visuals.window_highlight_topmost,
+                visuals.text_cursor.preview,
+                visuals.text_cursor.blink,
+                visuals.button_frame,
+                visuals.collapsing_header_frame,
+                visuals.indent_has_left_vline,
+                visuals.striped,
+                visuals.slider_trailing_fill,
+                visuals.image_loading_spinners,
+            ] = flags;
+            visuals.handle_shape = match handle_aspect_ratio {
+                None => HandleShape::Circle,
+                Some(aspect_ratio) => HandleShape::Rect { aspect_ratio },
+            };
+            visuals.interact_cursor = interact_cursor;
+            visuals.numeric_color_space = numeric_color_space;
+
+            visuals
+        })
+}
@@ -0,0 +1,24 @@
+use eframe::egui::{Color32, Style, Visuals};
+use egui_themer::css;
+
+#[test]
+fn dark_default() {
+    let mut style = Style {
+        visuals: Visuals::dark(),
+        ..Default::default()
+    };
+    style.visuals.window_shadow.color = Color32::from_black_alpha(96);
+    let css = css::generate_css(&style);
+
+    for property in [
+        "--egui-visuals-window-fill: #1b1b1b;",
+        "--egui-visuals-window-stroke: 1px solid #3c3c3c;",
+        "--egui-visuals-window-rounding: 6px 6px 6px 6px;",
+        "--egui-visuals-window-shadow: 10px 20px 15px 0px #00000060;",
+        "--egui-visuals-widgets-hovered-fg-stroke-color: #f0f0f0;",
+        "--egui-spacing-button-padding-x: 4px;",
+        "--egui-spacing-window-margin: 6px 6px 6px 6px;",
+    ] {
+        assert!(css.contains(property), "missing {property:?} in:\n{css}");
+    }
+}
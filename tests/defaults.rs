@@ -1,8 +1,9 @@
-use eframe::egui::{Style, Visuals};
+use eframe::egui::{Style, TextWrapMode, Visuals};
 
 use pretty_assertions::assert_eq;
 
 mod dark_default;
+mod dark_truncate;
 mod light_default;
 
 #[test]
@@ -32,3 +33,18 @@ fn light_default() {
         .unwrap(),
     );
 }
+
+#[test]
+fn dark_truncate() {
+    use dark_truncate;
+
+    assert_eq!(
+        serde_json::to_string_pretty(&dark_truncate::style()).unwrap(),
+        serde_json::to_string_pretty(&Style {
+            visuals: Visuals::dark(),
+            wrap_mode: Some(TextWrapMode::Truncate),
+            ..Default::default()
+        })
+        .unwrap(),
+    );
+}
@@ -0,0 +1,80 @@
+use eframe::egui::{style::ScrollStyle, Color32, Style, Visuals};
+use egui_themer::design_tokens;
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+mod common;
+
+fn style(visuals: Visuals) -> Style {
+    Style {
+        visuals,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn round_trip() {
+    let mut light = style(Visuals::light());
+    light.spacing = common::random_style(1).spacing;
+    let tokens = design_tokens::generate_tokens(&light);
+    assert!(design_tokens::is_design_tokens(&tokens));
+
+    // every spacing dimension comes from the tokens, the flags and opacities
+    // from the current style
+    let mut dark = style(Visuals::dark());
+    dark.spacing.indent_ends_with_horizontal_line = light.spacing.indent_ends_with_horizontal_line;
+    dark.spacing.scroll = ScrollStyle {
+        floating: light.spacing.scroll.floating,
+        foreground_color: light.spacing.scroll.foreground_color,
+        dormant_background_opacity: light.spacing.scroll.dormant_background_opacity,
+        active_background_opacity: light.spacing.scroll.active_background_opacity,
+        interact_background_opacity: light.spacing.scroll.interact_background_opacity,
+        dormant_handle_opacity: light.spacing.scroll.dormant_handle_opacity,
+        active_handle_opacity: light.spacing.scroll.active_handle_opacity,
+        interact_handle_opacity: light.spacing.scroll.interact_handle_opacity,
+        ..dark.spacing.scroll
+    };
+
+    let imported = design_tokens::import_tokens(&tokens, &dark);
+
+    assert_eq!(imported.skipped, []);
+    assert_eq!(design_tokens::generate_tokens(&imported.style), tokens);
+    assert_eq!(imported.style.spacing, light.spacing);
+}
+
+#[test]
+fn aliases_and_malformed_tokens() {
+    let document = json!({
+        "palette": {
+            "accent": { "$type": "color", "$value": "#ff8000" },
+        },
+        "visuals": {
+            "hyperlink_color": { "$type": "color", "$value": "{palette.accent}" },
+            "window_fill": { "$type": "color", "$value": "orange" },
+            "window_stroke": {
+                "$type": "border",
+                "$value": { "color": "{palette.accent}", "width": { "value": 2, "unit": "px" }, "style": "solid" },
+            },
+        },
+        "spacing": {
+            "indent": { "$type": "dimension", "$value": "1.5rem" },
+        },
+    });
+    let current = style(Visuals::dark());
+
+    let imported = design_tokens::import_tokens(&document, &current);
+    let visuals = &imported.style.visuals;
+
+    assert_eq!(visuals.hyperlink_color, Color32::from_rgb(255, 128, 0));
+    assert_eq!(visuals.window_stroke.color, Color32::from_rgb(255, 128, 0));
+    assert_eq!(visuals.window_stroke.width, 2.0);
+    assert_eq!(visuals.window_fill, current.visuals.window_fill);
+    assert_eq!(imported.style.spacing.indent, current.spacing.indent);
+
+    let skipped: Vec<_> = imported
+        .skipped
+        .iter()
+        .map(|field| field.path.as_str())
+        .collect();
+    assert_eq!(skipped, ["spacing.indent", "visuals.window_fill"]);
+}
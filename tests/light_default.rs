@@ -5,11 +5,13 @@ use eframe::egui;
 use egui::{
     epaint::Shadow,
     style::{
-        Interaction, ScrollStyle, Selection, Spacing, TextCursorStyle, WidgetVisuals, Widgets,
+        HandleShape, Interaction, NumericColorSpace, ScrollStyle, Selection, Spacing,
+        TextCursorStyle, WidgetVisuals, Widgets,
     },
     Color32, Margin, Rounding, Stroke, Style, Vec2, Visuals,
 };
 
+#[allow(clippy::excessive_precision)]
 pub fn style() -> Style {
     Style {
         // override the text styles here:
@@ -41,28 +43,44 @@ pub fn style() -> Style {
             indent: 18.0,
             interact_size: Vec2 { x: 40.0, y: 18.0 },
             slider_width: 100.0,
+            slider_rail_height: 8.0,
             combo_width: 100.0,
             text_edit_width: 280.0,
             icon_width: 14.0,
             icon_width_inner: 8.0,
             icon_spacing: 4.0,
+            default_area_size: Vec2 { x: 600.0, y: 400.0 },
             tooltip_width: 500.0,
+            menu_width: 400.0,
+            menu_spacing: 2.0,
             indent_ends_with_horizontal_line: false,
             combo_height: 200.0,
             scroll: ScrollStyle {
+                floating: true,
                 bar_width: 10.0,
                 handle_min_length: 12.0,
                 bar_inner_margin: 4.0,
                 bar_outer_margin: 0.0,
-                ..Default::default()
+                floating_width: 2.0,
+                floating_allocated_width: 0.0,
+                foreground_color: true,
+                dormant_background_opacity: 0.0,
+                active_background_opacity: 0.4000000059604645,
+                interact_background_opacity: 0.699999988079071,
+                dormant_handle_opacity: 0.0,
+                active_handle_opacity: 0.6000000238418579,
+                interact_handle_opacity: 1.0,
             },
-            ..Default::default()
         },
         interaction: Interaction {
+            interact_radius: 5.0,
             resize_grab_radius_side: 5.0,
             resize_grab_radius_corner: 10.0,
             show_tooltips_only_when_still: true,
-            ..Default::default()
+            tooltip_delay: 0.5,
+            tooltip_grace_time: 0.20000000298023224,
+            selectable_labels: true,
+            multi_widget_text_select: true,
         },
         visuals: Visuals {
             dark_mode: false,
@@ -194,6 +212,7 @@ pub fn style() -> Style {
                 width: 1.0,
                 color: Color32::from_rgba_premultiplied(190, 190, 190, 255),
             },
+            window_highlight_topmost: true,
             menu_rounding: Rounding {
                 nw: 6.0,
                 ne: 6.0,
@@ -214,7 +233,9 @@ pub fn style() -> Style {
                     color: Color32::from_rgba_premultiplied(0, 83, 125, 255),
                 },
                 preview: false,
-                ..Default::default()
+                blink: true,
+                on_duration: 0.5,
+                off_duration: 0.5,
             },
             clip_rect_margin: 3.0,
             button_frame: true,
@@ -222,10 +243,15 @@ pub fn style() -> Style {
             indent_has_left_vline: true,
             striped: false,
             slider_trailing_fill: false,
-            ..Default::default()
+            handle_shape: HandleShape::Circle,
+            interact_cursor: None,
+            image_loading_spinners: true,
+            numeric_color_space: NumericColorSpace::GammaByte,
         },
         animation_time: 0.0833333358168602,
         explanation_tooltips: false,
+        url_in_tooltip: false,
+        always_scroll_the_only_direction: false,
         ..Default::default()
     }
 }
@@ -0,0 +1,130 @@
+use eframe::egui::{Color32, Style, TextWrapMode, Vec2, Visuals};
+use egui_themer::migrate;
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+mod common;
+
+#[test]
+fn current_styles_need_no_migration() {
+    for seed in 0..16 {
+        let style = common::random_style(seed);
+        let json = serde_json::to_vec(&style).unwrap();
+
+        assert_eq!(
+            migrate::import_style(&json).unwrap().migrations,
+            Vec::<String>::new()
+        );
+    }
+}
+
+/// A light theme in the shape egui 0.23 exported it, trimmed to the fields
+/// that have since been renamed or reshaped.
+#[test]
+fn egui_0_23_style() {
+    let json = json!({
+        "wrap": false,
+        "spacing": {
+            "item_spacing": { "x": 10.0, "y": 5.0 },
+            "scroll_bar_width": 12.0,
+            "scroll_handle_min_length": 20.0,
+        },
+        "visuals": {
+            "dark_mode": false,
+            "window_shadow": { "extrusion": 24.0, "color": [0, 0, 0, 64] },
+            "text_cursor": { "width": 3.0, "color": [255, 0, 0, 255] },
+            "text_cursor_preview": true,
+            "widgets": {
+                "hovered": {
+                    "bg_fill": [1, 2, 3, 255],
+                    "bg_stroke": { "width": 1.0, "color": [0, 0, 0, 255] },
+                    "rounding": { "nw": 2.0, "ne": 2.0, "sw": 2.0, "se": 2.0 },
+                    "fg_stroke": { "width": 1.0, "color": [0, 0, 0, 255] },
+                    "expansion": 1.0,
+                },
+            },
+        },
+    });
+
+    let migrated = migrate::import_style(&serde_json::to_vec(&json).unwrap()).unwrap();
+    let style: Style = migrated.style;
+    let light = Visuals::light();
+
+    assert_eq!(style.wrap_mode, Some(TextWrapMode::Extend));
+    assert_eq!(style.spacing.item_spacing, Vec2::new(10.0, 5.0));
+    assert_eq!(style.spacing.scroll.bar_width, 12.0);
+    assert_eq!(style.spacing.scroll.handle_min_length, 20.0);
+    assert_eq!(style.visuals.window_shadow.blur, 24.0);
+    assert_eq!(style.visuals.window_shadow.offset, Vec2::ZERO);
+    assert_eq!(style.visuals.popup_shadow, light.popup_shadow);
+    assert_eq!(style.visuals.text_cursor.stroke.width, 3.0);
+    assert_eq!(style.visuals.text_cursor.stroke.color, Color32::RED);
+    assert!(style.visuals.text_cursor.preview);
+    assert_eq!(
+        style.visuals.widgets.hovered.bg_fill,
+        Color32::from_rgb(1, 2, 3)
+    );
+    assert_eq!(
+        style.visuals.widgets.hovered.weak_bg_fill,
+        light.widgets.hovered.weak_bg_fill
+    );
+
+    for migration in [
+        "converted wrap: false to wrap_mode: Extend",
+        "moved spacing.scroll_bar_width to spacing.scroll.bar_width",
+        "converted visuals.window_shadow.extrusion to visuals.window_shadow.blur",
+        "moved visuals.text_cursor_preview to visuals.text_cursor.preview",
+        "filled missing visuals.widgets.hovered.weak_bg_fill from the defaults",
+    ] {
+        assert!(
+            migrated.migrations.iter().any(|m| m == migration),
+            "missing migration {migration:?} in {:#?}",
+            migrated.migrations
+        );
+    }
+}
+
+#[test]
+fn lenient_import_skips_bad_fields() {
+    let current = common::random_style(7);
+    let json = json!({
+        "spacing": {
+            "item_spacing": { "x": "wide", "y": 4.0 },
+            "indent": 30.0,
+        },
+        "visuals": {
+            "hyperlink_color": [1, 2, 3],
+            "faint_bg_color": [1, 2, 3, 255],
+            "sparkles": true,
+        },
+    });
+
+    let migrated =
+        migrate::import_style_lenient(&serde_json::to_vec(&json).unwrap(), &current).unwrap();
+    let style = migrated.style;
+
+    assert_eq!(style.spacing.item_spacing.x, current.spacing.item_spacing.x);
+    assert_eq!(style.spacing.item_spacing.y, 4.0);
+    assert_eq!(style.spacing.indent, 30.0);
+    assert_eq!(
+        style.visuals.hyperlink_color,
+        current.visuals.hyperlink_color
+    );
+    assert_eq!(style.visuals.faint_bg_color, Color32::from_rgb(1, 2, 3));
+    assert_eq!(style.visuals.window_shadow, current.visuals.window_shadow);
+
+    let skipped: Vec<_> = migrated
+        .skipped
+        .iter()
+        .map(|field| field.path.as_str())
+        .collect();
+    assert_eq!(
+        skipped,
+        [
+            "spacing.item_spacing.x",
+            "visuals.hyperlink_color",
+            "visuals.sparkles"
+        ]
+    );
+    assert_eq!(migrated.skipped[2].reason, "unknown field");
+}
@@ -0,0 +1,116 @@
+use eframe::egui::Style;
+use egui_themer::{
+    codegen::{self, Section, SourceOptions},
+    migrate,
+    notes::{self, Notes},
+};
+use pretty_assertions::assert_eq;
+
+mod common;
+
+fn notes() -> Notes {
+    Notes::from([
+        (
+            "visuals.widgets.hovered.bg_fill".to_owned(),
+            "matches brand guideline v3 primary".to_owned(),
+        ),
+        (
+            "spacing.item_spacing".to_owned(),
+            "roomier than the default\n\nfor touch screens".to_owned(),
+        ),
+        ("animation_time".to_owned(), "snappy".to_owned()),
+        ("text_styles".to_owned(), "Inter everywhere".to_owned()),
+    ])
+}
+
+/// The trimmed line before the first line setting `field` after `after`.
+fn comment_before(source: &str, after: &str, field: &str) -> String {
+    let lines: Vec<&str> = source.lines().map(str::trim).collect();
+    let start = lines
+        .iter()
+        .position(|line| line.starts_with(after))
+        .unwrap();
+    let at = start
+        + lines[start..]
+            .iter()
+            .position(|line| line.starts_with(field))
+            .unwrap();
+    lines[at - 1].to_owned()
+}
+
+#[test]
+fn rust_comments() {
+    let source = codegen::generate_source(&Style::default(), &SourceOptions::default()).unwrap();
+    let annotated = notes::annotate_rust(&source, "", &notes());
+
+    assert_eq!(
+        comment_before(&annotated, "hovered:", "bg_fill:"),
+        "// matches brand guideline v3 primary"
+    );
+    assert_eq!(
+        comment_before(&annotated, "spacing:", "item_spacing:"),
+        "// for touch screens"
+    );
+    assert_eq!(
+        comment_before(&annotated, "animation_time", "animation_time:"),
+        "// snappy"
+    );
+    assert_eq!(
+        comment_before(&annotated, "// set your text styles", "// text_styles:"),
+        "// Inter everywhere"
+    );
+    // only the hovered state's fill is noted
+    assert_eq!(annotated.matches("brand guideline").count(), 1);
+    assert_eq!(
+        annotated.lines().count(),
+        source.lines().count() + 6,
+        "one line per line of each note"
+    );
+
+    let snippet =
+        codegen::generate_snippet(&Style::default(), Section::Widgets, &Default::default())
+            .unwrap();
+    let annotated = notes::annotate_rust(&snippet, "visuals.widgets", &notes());
+    assert_eq!(
+        comment_before(&annotated, "hovered:", "bg_fill:"),
+        "// matches brand guideline v3 primary"
+    );
+}
+
+#[test]
+fn json_comments_are_ignored_on_import() {
+    let style = common::random_style(3);
+    let mut value = serde_json::to_value(&style).unwrap();
+    notes::annotate_json(&mut value, "", &notes());
+
+    assert_eq!(
+        value["visuals"]["widgets"]["hovered"]["$comment"]["bg_fill"],
+        "matches brand guideline v3 primary"
+    );
+    assert_eq!(value["$comment"]["animation_time"], "snappy");
+
+    let imported = migrate::import_style(value.to_string().as_bytes()).unwrap();
+    assert_eq!(imported.migrations, Vec::<String>::new());
+    assert_eq!(
+        serde_json::to_string_pretty(&imported.style).unwrap(),
+        serde_json::to_string_pretty(&style).unwrap()
+    );
+
+    let lenient =
+        migrate::import_style_lenient(value.to_string().as_bytes(), &Style::default()).unwrap();
+    assert_eq!(lenient.skipped, Vec::new());
+}
+
+#[test]
+fn project_round_trip() {
+    let style = common::random_style(5);
+    let project = notes::save_project(&style, &notes()).unwrap();
+    let (migrated, loaded) = notes::load_project(project.as_bytes()).unwrap();
+
+    assert_eq!(loaded, notes());
+    assert_eq!(
+        serde_json::to_string_pretty(&migrated.style).unwrap(),
+        serde_json::to_string_pretty(&style).unwrap()
+    );
+    assert!(notes::load_project(b"{\"visuals\": {}}").is_err());
+}
@@ -0,0 +1,240 @@
+use std::{
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use eframe::egui::Style;
+use egui_themer::{
+    clipboard::{self, Format},
+    codegen::{self, EguiVersion, Section, SourceOptions},
+    design_tokens, share,
+};
+use pretty_assertions::assert_eq;
+use proptest::prelude::*;
+
+mod common;
+
+/// How many random styles are compiled into the generated Rust fixture.
+const RUST_SOURCE_STYLES: u64 = 16;
+
+fn to_json(style: &Style) -> String {
+    serde_json::to_string_pretty(style).unwrap()
+}
+
+proptest! {
+    #[test]
+    fn json(style in common::style()) {
+        for json in [
+            serde_json::to_string(&style).unwrap(),
+            serde_json::to_string_pretty(&style).unwrap(),
+        ] {
+            let (format, migrated) = clipboard::paste(&json, &Style::default()).unwrap();
+            prop_assert_eq!(format, Format::Json(None));
+            prop_assert_eq!(to_json(&migrated.style), to_json(&style));
+        }
+    }
+
+    #[test]
+    fn rust(style in common::style()) {
+        let source = codegen::generate_source(&style, &options(EguiVersion::V0_28)).unwrap();
+        let (format, migrated) = clipboard::paste(&source, &Style::default()).unwrap();
+        prop_assert_eq!(format, Format::Rust(None));
+        prop_assert_eq!(to_json(&migrated.style), to_json(&style));
+    }
+
+    #[test]
+    fn rust_snippets(style in common::style(), current in common::style()) {
+        for section in Section::ALL {
+            let snippet = codegen::generate_snippet(&style, section, &SourceOptions::default())
+                .unwrap();
+            let (format, migrated) = clipboard::paste(&snippet, &current).unwrap();
+            prop_assert_eq!(format, Format::Rust(Some(section)));
+
+            let mut expected = current.clone();
+            section.copy(&style, &mut expected);
+            prop_assert_eq!(to_json(&migrated.style), to_json(&expected));
+        }
+    }
+
+    #[test]
+    fn design_tokens(style in common::style(), current in common::style()) {
+        let tokens = design_tokens::generate_tokens(&style);
+        let (format, migrated) = clipboard::paste(&tokens.to_string(), &current).unwrap();
+        prop_assert_eq!(format, Format::DesignTokens);
+        prop_assert_eq!(migrated.skipped, Vec::new());
+        prop_assert_eq!(design_tokens::generate_tokens(&migrated.style), tokens);
+    }
+
+    #[test]
+    fn share_link(style in common::style()) {
+        let link = share::link("https://example.com/themer/", &style).unwrap();
+        let (format, migrated) = clipboard::paste(&link, &Style::default()).unwrap();
+        prop_assert_eq!(format, Format::ShareLink);
+        prop_assert_eq!(to_json(&migrated.style), to_json(&style));
+    }
+}
+
+/// Compile the generated source for a batch of random styles into a scratch
+/// crate, run it, and compare what the generated `style()` functions return.
+#[test]
+fn rust_source() {
+    let styles: Vec<Style> = (0..RUST_SOURCE_STYLES).map(common::random_style).collect();
+
+    let dir = scratch_crate("roundtrip", EguiVersion::V0_28, &styles);
+    fs::copy(
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.lock"),
+        dir.join("Cargo.lock"),
+    )
+    .unwrap();
+
+    let mut main = String::from("\nfn main() {\n    let styles = vec![\n");
+    for i in 0..styles.len() {
+        main.push_str(&format!("        (*style_{i}::style_arc()).clone(),\n"));
+    }
+    main.push_str("    ];\n    println!(\"{}\", serde_json::to_string(&styles).unwrap());\n}\n");
+    let mut file = fs::OpenOptions::new()
+        .append(true)
+        .open(dir.join("src/main.rs"))
+        .unwrap();
+    file.write_all(main.as_bytes()).unwrap();
+
+    let output = cargo(&dir, &["run"]);
+    let generated: Vec<Style> = serde_json::from_slice(&output).unwrap();
+    for (generated, style) in generated.iter().zip(&styles) {
+        assert_eq!(to_json(generated), to_json(style));
+    }
+    assert_eq!(generated.len(), styles.len());
+}
+
+/// Type-check the generated source for every other egui version the exporter targets.
+#[test]
+#[ignore = "downloads every other egui version from crates.io"]
+fn rust_source_versions() {
+    let styles: Vec<Style> = (0..4).map(common::random_style).collect();
+
+    for version in EguiVersion::ALL {
+        if version == EguiVersion::V0_28 {
+            continue;
+        }
+
+        let name = format!("roundtrip_{}", version.name().replace('.', "_"));
+        let dir = scratch_crate(&name, version, &styles);
+        fs::write(
+            dir.join("src/main.rs"),
+            fs::read_to_string(dir.join("src/main.rs")).unwrap() + "\nfn main() {}\n",
+        )
+        .unwrap();
+
+        cargo(&dir, &["check"]);
+    }
+}
+
+/// Build an exported crate without warnings, with and without its `eframe`
+/// feature, and call each of its helpers.
+#[test]
+fn cargo_crate() {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("exported-theme");
+    let files = codegen::generate_crate(
+        &common::random_style(0),
+        "exported-theme",
+        &options(EguiVersion::V0_28),
+    )
+    .unwrap();
+    for (path, contents) in files {
+        let path = dir.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, contents).unwrap();
+    }
+    fs::copy(
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.lock"),
+        dir.join("Cargo.lock"),
+    )
+    .unwrap();
+    // every helper has to be reachable from outside the crate
+    fs::create_dir_all(dir.join("tests")).unwrap();
+    fs::write(
+        dir.join("tests/helpers.rs"),
+        r#"#[test]
+fn helpers() {
+    let ctx = egui::Context::default();
+    exported_theme::apply(&ctx);
+    ctx.set_style(exported_theme::style_arc());
+    ctx.set_style(exported_theme::STYLE.clone());
+    ctx.set_style(exported_theme::style());
+    ctx.set_fonts(exported_theme::fonts());
+}
+"#,
+    )
+    .unwrap();
+
+    cargo(&dir, &["test"]);
+    cargo(&dir, &["check", "--all-targets", "--features", "eframe"]);
+}
+
+/// Write a binary crate with one module per style, generated for `version`.
+fn scratch_crate(name: &str, version: EguiVersion, styles: &[Style]) -> PathBuf {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join(name);
+    fs::create_dir_all(dir.join("src")).unwrap();
+
+    fs::write(
+        dir.join("Cargo.toml"),
+        format!(
+            r#"[package]
+name = "{name}"
+version = "0.0.0"
+edition = "2021"
+publish = false
+
+[dependencies]
+egui = {{ version = "{}", features = ["serde"] }}
+serde_json = "1.0"
+
+[workspace]
+"#,
+            version.name()
+        ),
+    )
+    .unwrap();
+
+    let mut main = String::from("#![allow(dead_code)]\n\n");
+    for (i, style) in styles.iter().enumerate() {
+        let source = codegen::generate_source(style, &options(version)).unwrap();
+        fs::write(dir.join(format!("src/style_{i}.rs")), source).unwrap();
+        main.push_str(&format!("mod style_{i};\n"));
+    }
+    fs::write(dir.join("src/main.rs"), main).unwrap();
+
+    dir
+}
+
+/// Source options for `version` with every optional helper enabled.
+fn options(version: EguiVersion) -> SourceOptions {
+    SourceOptions {
+        eframe: false,
+        version,
+        lazy_static: true,
+        arc: true,
+        apply: Some(1.5),
+    }
+}
+
+/// Run a cargo subcommand quietly in `dir`, denying warnings, and return its stdout.
+fn cargo(dir: &Path, args: &[&str]) -> Vec<u8> {
+    let output = Command::new(std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_owned()))
+        .args(args)
+        .arg("--quiet")
+        .current_dir(dir)
+        .env("CARGO_TARGET_DIR", dir.join("target"))
+        .env("RUSTFLAGS", "-D warnings")
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "generated source failed to build:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    output.stdout
+}
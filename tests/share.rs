@@ -0,0 +1,32 @@
+use egui_themer::share;
+use pretty_assertions::assert_eq;
+
+mod common;
+
+#[test]
+fn link_round_trip() {
+    for seed in 0..8 {
+        let style = common::random_style(seed);
+        let link = share::link("https://example.com/themer/#old", &style).unwrap();
+        assert!(link.starts_with("https://example.com/themer/#theme="));
+
+        for pasted in [
+            link.as_str(),
+            link.split_once('#').unwrap().1,
+            &share::encode(&style).unwrap(),
+        ] {
+            let migrated = share::decode(pasted).unwrap();
+            assert_eq!(
+                serde_json::to_string_pretty(&migrated.style).unwrap(),
+                serde_json::to_string_pretty(&style).unwrap()
+            );
+            assert_eq!(migrated.migrations, Vec::<String>::new());
+        }
+    }
+}
+
+#[test]
+fn rejects_garbage() {
+    assert!(share::decode("https://example.com/#theme=not*base64").is_err());
+    assert!(share::decode("aGVsbG8").is_err());
+}